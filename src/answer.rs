@@ -0,0 +1,83 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(i) => write!(f, "{i}"),
+            Answer::BigInteger(i) => write!(f, "{i}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+// Integers only become big integers when they don't fit in an i64, so the same answer always
+// compares equal however the problem happened to compute it
+impl From<i128> for Answer {
+    fn from(i: i128) -> Self {
+        i64::try_from(i).map_or(Answer::BigInteger(i), Answer::Integer)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(i: u128) -> Self {
+        i128::try_from(i).map_or(Answer::Text(i.to_string()), Answer::from)
+    }
+}
+
+macro_rules! impl_from_small_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(i: $t) -> Self {
+                    Answer::from(i as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_small_integer!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_store_integers_that_fit_as_plain_integers() {
+        assert_eq!(Answer::from(31u128), Answer::Integer(31));
+        assert_eq!(Answer::from(31usize), Answer::from(31));
+        assert_eq!(Answer::from(-4i64), Answer::Integer(-4));
+    }
+
+    #[test]
+    fn should_fall_back_to_big_integers() {
+        let big = i64::MAX as u128 + 1;
+
+        assert_eq!(Answer::from(big), Answer::BigInteger(big as i128));
+        assert_eq!(Answer::from(big).to_string(), "9223372036854775808");
+    }
+
+    #[test]
+    fn should_display_text_as_is() {
+        assert_eq!(Answer::from("4,6,3,5,6,3,5,2,1,0").to_string(), "4,6,3,5,6,3,5,2,1,0");
+    }
+}
@@ -0,0 +1,241 @@
+use crate::problems::{AnyProblem, ProblemError, SolveContext};
+use crate::runner::{self, format_duration, PartOutcome};
+use crate::table::{format_table, Align};
+use crate::Part;
+use serde::Serialize;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "1"),
+            Phase::Part2 => write!(f, "2"),
+        }
+    }
+}
+
+/// How long one phase took over every run, in nanoseconds so small changes still show up
+#[derive(Debug, PartialEq, Serialize)]
+pub struct PhaseTimings {
+    pub phase: Phase,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl PhaseTimings {
+    fn from_durations(phase: Phase, durations: &mut [Duration]) -> Self {
+        durations.sort();
+
+        let middle = durations.len() / 2;
+        let median = if durations.len().is_multiple_of(2) {
+            (durations[middle - 1] + durations[middle]) / 2
+        } else {
+            durations[middle]
+        };
+
+        PhaseTimings {
+            phase,
+            min_ns: durations[0].as_nanos() as u64,
+            median_ns: median.as_nanos() as u64,
+            max_ns: durations[durations.len() - 1].as_nanos() as u64,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DayBenchmark {
+    pub day: usize,
+    /// Phases that ran, leaving out parts that aren't implemented
+    pub phases: Vec<PhaseTimings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<BenchError>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct BenchError {
+    pub phase: Phase,
+    pub message: String,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Benchmark {
+    pub year: i32,
+    pub runs: usize,
+    pub days: Vec<DayBenchmark>,
+}
+
+/// Parses and solves both parts `runs` times without displaying anything, stopping at the first
+/// failure
+pub fn bench_day(day: usize, problem: &dyn AnyProblem, input: &str, runs: usize) -> DayBenchmark {
+    let mut durations: Vec<(Phase, Vec<Duration>)> = vec![
+        (Phase::Parse, vec![]),
+        (Phase::Part1, vec![]),
+        (Phase::Part2, vec![]),
+    ];
+
+    let error = runner::with_silenced_panics(|| {
+        for _ in 0..runs {
+            let (prepared, parse_duration) = match runner::run_parse(problem, input) {
+                Ok(parsed) => parsed,
+                Err(ProblemError::Unimplemented) => return None,
+                Err(e) => return Some(BenchError {
+                    phase: Phase::Parse,
+                    message: e.to_string(),
+                }),
+            };
+            durations[0].1.push(parse_duration);
+
+            for (part, (phase, part_durations)) in Part::ALL.into_iter().zip(&mut durations[1..]) {
+                match runner::run_part(|| prepared.solve(&SolveContext::headless(part))) {
+                    PartOutcome::Solved { duration, .. } => part_durations.push(duration),
+                    PartOutcome::Unimplemented => {}
+                    PartOutcome::Failed(e) => return Some(BenchError {
+                        phase: *phase,
+                        message: e.to_string(),
+                    }),
+                }
+            }
+        }
+
+        None
+    });
+
+    DayBenchmark {
+        day,
+        phases: durations
+            .iter_mut()
+            .filter(|(_, durations)| !durations.is_empty())
+            .map(|(phase, durations)| PhaseTimings::from_durations(*phase, durations))
+            .collect(),
+        error,
+    }
+}
+
+pub fn format_benchmark(benchmark: &Benchmark) -> String {
+    let nanos = |ns: u64| format_duration(&Duration::from_nanos(ns));
+
+    let cells = benchmark
+        .days
+        .iter()
+        .flat_map(|day| {
+            day.phases
+                .iter()
+                .map(|timings| {
+                    [
+                        day.day.to_string(),
+                        timings.phase.to_string(),
+                        nanos(timings.min_ns),
+                        nanos(timings.median_ns),
+                        nanos(timings.max_ns),
+                    ]
+                })
+                .chain(day.error.iter().map(|error| {
+                    [
+                        day.day.to_string(),
+                        error.phase.to_string(),
+                        format!("failed ({})", error.message),
+                        String::new(),
+                        String::new(),
+                    ]
+                }))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    format_table(
+        ["Day", "Phase", "Min", "Median", "Max"],
+        [Align::Right, Align::Right, Align::Right, Align::Right, Align::Right],
+        &cells,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_take_median_of_even_number_of_runs_as_the_mean_of_the_middle_two() {
+        let mut durations = [4, 1, 3, 10].map(Duration::from_nanos);
+
+        assert_eq!(
+            PhaseTimings::from_durations(Phase::Parse, &mut durations),
+            PhaseTimings {
+                phase: Phase::Parse,
+                min_ns: 1,
+                median_ns: 3,
+                max_ns: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn should_leave_out_unimplemented_parts() {
+        struct ParseOnly;
+
+        impl crate::problems::Problem for ParseOnly {
+            const DAY: usize = 1;
+            const TITLE: &'static str = "Test";
+            type Parsed = ();
+
+            fn parse(&self, _input: &str) -> Result<Self::Parsed, ProblemError> {
+                Ok(())
+            }
+        }
+
+        let benchmark = bench_day(1, &ParseOnly, "", 3);
+
+        assert_eq!(
+            benchmark.phases.iter().map(|t| t.phase).collect::<Vec<_>>(),
+            vec![Phase::Parse]
+        );
+        assert_eq!(benchmark.error, None);
+    }
+
+    #[test]
+    fn should_format_timings_per_phase() {
+        let benchmark = Benchmark {
+            year: 2024,
+            runs: 5,
+            days: vec![DayBenchmark {
+                day: 3,
+                phases: vec![PhaseTimings {
+                    phase: Phase::Part1,
+                    min_ns: 1_000_000,
+                    median_ns: 1_500_000,
+                    max_ns: 12_000_000,
+                }],
+                error: Some(BenchError {
+                    phase: Phase::Part2,
+                    message: String::from("no way through"),
+                }),
+            }],
+        };
+
+        assert_eq!(
+            format_benchmark(&benchmark),
+            [
+                "Day  Phase                      Min  Median      Max",
+                "  3      1                   1.00ms  1.50ms  12.00ms",
+                "  3      2  failed (no way through)",
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            serde_json::to_string(&benchmark).unwrap(),
+            "{\"year\":2024,\"runs\":5,\"days\":[{\"day\":3,\"phases\":[{\"phase\":\"part1\",\
+            \"min_ns\":1000000,\"median_ns\":1500000,\"max_ns\":12000000}],\
+            \"error\":{\"phase\":\"part2\",\"message\":\"no way through\"}}]}"
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::AOC_BASE_URL;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use std::cell::Cell;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "github.com/ajablonski/advent-of-code-2024 ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub base_url: String,
+    pub user_agent: String,
+    pub min_delay: Duration,
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            base_url: String::from(AOC_BASE_URL),
+            user_agent: String::from(DEFAULT_USER_AGENT),
+            min_delay: Duration::from_secs(1),
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(2),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+pub struct AocClient {
+    client: Client,
+    config: ClientConfig,
+    session_token: String,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl AocClient {
+    pub fn new(config: ClientConfig, session_token: &str) -> crate::Result<AocClient> {
+        let client = Client::builder()
+            .user_agent(config.user_agent.as_str())
+            .timeout(config.timeout)
+            .build()?;
+
+        Ok(AocClient {
+            client,
+            config,
+            session_token: session_token.to_string(),
+            last_request: Cell::new(None),
+        })
+    }
+
+    pub fn get(&self, path: &str) -> crate::Result<Response> {
+        let mut attempt = 0;
+
+        loop {
+            let result = self.send(self.client.get(self.url(path)));
+
+            let should_retry = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(e) => e.is_timeout(),
+            };
+
+            if !should_retry || attempt >= self.config.max_retries {
+                return Ok(result?);
+            }
+
+            sleep(self.config.initial_backoff * 2u32.pow(attempt));
+            attempt += 1;
+        }
+    }
+
+    // Submissions are never retried, since a request that timed out may still have been counted
+    pub fn post_form(&self, path: &str, form: &[(&str, String)]) -> crate::Result<Response> {
+        Ok(self.send(self.client.post(self.url(path)).form(form))?)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.config.base_url)
+    }
+
+    fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        if let Some(last_request) = self.last_request.get() {
+            sleep(self.config.min_delay.saturating_sub(last_request.elapsed()));
+        }
+
+        let result = request
+            .header("Cookie", format!("session={}", self.session_token))
+            .send();
+        self.last_request.set(Some(Instant::now()));
+
+        result
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn test_config(base_url: &str) -> ClientConfig {
+    ClientConfig {
+        base_url: base_url.to_string(),
+        min_delay: Duration::ZERO,
+        initial_backoff: Duration::from_millis(1),
+        timeout: Duration::from_secs(5),
+        ..ClientConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::TestServer;
+
+    #[test]
+    fn should_identify_itself_and_send_session_cookie() {
+        let server = TestServer::start(vec![(200, "ok")]);
+        let config = ClientConfig {
+            user_agent: String::from("test-agent (me@example.com)"),
+            ..test_config(&server.base_url)
+        };
+
+        AocClient::new(config, "abc123").unwrap().get("/x").unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].header("User-Agent"),
+            Some("test-agent (me@example.com)")
+        );
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+    }
+
+    #[test]
+    fn should_wait_between_requests() {
+        let server = TestServer::start(vec![(200, "1"), (200, "2")]);
+        let config = ClientConfig {
+            min_delay: Duration::from_millis(200),
+            ..test_config(&server.base_url)
+        };
+        let client = AocClient::new(config, "abc123").unwrap();
+
+        let start = Instant::now();
+        client.get("/1").unwrap();
+        client.get("/2").unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn should_retry_server_errors_until_success() {
+        let server = TestServer::start(vec![(503, "busy"), (500, "oops"), (200, "done")]);
+        let client = AocClient::new(test_config(&server.base_url), "abc123").unwrap();
+
+        let response = client.get("/input").unwrap();
+
+        assert_eq!(response.text().unwrap(), "done");
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn should_give_up_after_max_retries() {
+        let server = TestServer::start(vec![(500, "1"), (500, "2"), (500, "3"), (200, "4")]);
+        let config = ClientConfig {
+            max_retries: 2,
+            ..test_config(&server.base_url)
+        };
+        let client = AocClient::new(config, "abc123").unwrap();
+
+        let response = client.get("/input").unwrap();
+
+        assert_eq!(response.status().as_u16(), 500);
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn should_not_retry_client_errors() {
+        let server = TestServer::start(vec![(404, "missing"), (200, "unused")]);
+        let client = AocClient::new(test_config(&server.base_url), "abc123").unwrap();
+
+        let response = client.get("/input").unwrap();
+
+        assert_eq!(response.status().as_u16(), 404);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn should_retry_timeouts() {
+        let server = TestServer::start_with_delays(vec![
+            (200, "slow", Duration::from_millis(500)),
+            (200, "fast", Duration::ZERO),
+        ]);
+        let config = ClientConfig {
+            timeout: Duration::from_millis(100),
+            ..test_config(&server.base_url)
+        };
+        let client = AocClient::new(config, "abc123").unwrap();
+
+        let response = client.get("/input").unwrap();
+
+        assert_eq!(response.text().unwrap(), "fast");
+    }
+
+    #[test]
+    fn should_not_retry_posts() {
+        let server = TestServer::start(vec![(502, "bad gateway"), (200, "unused")]);
+        let client = AocClient::new(test_config(&server.base_url), "abc123").unwrap();
+
+        let response = client
+            .post_form("/answer", &[("level", String::from("1"))])
+            .unwrap();
+
+        assert_eq!(response.status().as_u16(), 502);
+        assert_eq!(server.requests().len(), 1);
+    }
+}
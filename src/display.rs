@@ -1,16 +1,27 @@
 use std::collections::VecDeque;
-use crate::Event;
+use std::fmt;
+use crate::problems::ProblemError;
+use crate::display::grid_view::{GridScroll, GridView};
+use crate::display::log_pane::LogPane;
+use crate::{Answer, Event};
 use crossterm::event;
-use ratatui::layout::{Constraint, Layout};
-use ratatui::text::Line;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::text::Text;
 use ratatui::widgets::{List, Paragraph};
-use ratatui::{Frame, TerminalOptions, Viewport};
+use ratatui::backend::Backend;
+use ratatui::{DefaultTerminal, Frame, Terminal, TerminalOptions, Viewport};
 use std::sync::mpsc;
 use std::thread;
-use std::thread::sleep;
 use std::time::{Duration, Instant};
 use crate::problems::common::Grid;
 
+mod grid_view;
+
+mod log_pane;
+
+pub use grid_view::GridStyle;
+pub use log_pane::DEFAULT_LOG_CAPACITY;
+
 pub fn input_handling(tx: mpsc::Sender<Event>) {
     let tick_rate = Duration::from_millis(200);
     let new_tx = tx.clone();
@@ -23,8 +34,9 @@ pub fn input_handling(tx: mpsc::Sender<Event>) {
             if event::poll(timeout).unwrap() {
                 match event::read().unwrap() {
                     event::Event::Key(key) => new_tx.send(Event::Input(key)).unwrap(),
+                    event::Event::Resize(..) => new_tx.send(Event::Resize).unwrap(),
                     _ => {}
-                };
+                }
             }
             if last_tick.elapsed() >= tick_rate {
                 new_tx.send(Event::Tick).unwrap();
@@ -34,130 +46,527 @@ pub fn input_handling(tx: mpsc::Sender<Event>) {
     });
 }
 
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum PartStatus {
+    #[default]
+    Pending,
+    NotRun,
+    Solved(Answer),
+    Failed(ProblemError),
+}
+
+impl fmt::Display for PartStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartStatus::Pending => write!(f, "pending"),
+            PartStatus::NotRun => write!(f, "not run"),
+            PartStatus::Solved(answer) => write!(f, "{answer}"),
+            PartStatus::Failed(ProblemError::Unimplemented) => write!(f, "not implemented"),
+            PartStatus::Failed(e) => write!(f, "failed ({e})"),
+        }
+    }
+}
+
+#[derive(Default)]
 pub struct AppDisplayState {
-    pub rows: VecDeque<Line<'static>>,
-    pub part_1_result: Option<u128>,
-    pub part_2_result: Option<u128>,
-    pub grid: Option<Grid<char>>
+    pub part_1_result: PartStatus,
+    pub part_2_result: PartStatus,
+    /// How long each part took, once it has finished
+    pub part_1_duration: Option<Duration>,
+    pub part_2_duration: Option<Duration>,
+    pub grid: Option<Grid<char>>,
+    /// Boxed to keep display updates small, since most of them don't change it
+    pub grid_style: Option<Box<GridStyle>>,
+    /// How far through the current part the solver is, from 0.0 to 1.0
+    pub progress: Option<f64>,
+    /// Set once both parts have been run
+    pub finished: bool,
 }
 
 impl AppDisplayState {
-    pub fn part_1_only(i: u128) -> Self {
+    pub fn part_1_only(answer: impl Into<Answer>) -> Self {
         AppDisplayState {
-            part_1_result: Some(i),
-            part_2_result: None,
-            rows: VecDeque::new(),
-            grid: None
+            part_1_result: PartStatus::Solved(answer.into()),
+            part_2_result: PartStatus::Pending,
+            part_1_duration: None,
+            part_2_duration: None,
+            grid: None,
+            grid_style: None,
+            progress: None,
+            finished: false,
         }
     }
 
-    pub fn part_2_only(i: u128) -> Self {
+    pub fn part_2_only(answer: impl Into<Answer>) -> Self {
         AppDisplayState {
-            part_1_result: None,
-            part_2_result: Some(i),
-            rows: VecDeque::new(),
-            grid: None
+            part_1_result: PartStatus::Pending,
+            part_2_result: PartStatus::Solved(answer.into()),
+            part_1_duration: None,
+            part_2_duration: None,
+            grid: None,
+            grid_style: None,
+            progress: None,
+            finished: false,
         }
     }
 
-    pub fn grid_update(g: Grid<char>) -> Self {
+    pub fn grid_update(g: Grid<char>, style: GridStyle) -> Self {
         AppDisplayState {
-            part_1_result: None,
-            part_2_result: None,
-            rows: VecDeque::new(),
-            grid: Some(g)
+            part_1_result: PartStatus::Pending,
+            part_2_result: PartStatus::Pending,
+            part_1_duration: None,
+            part_2_duration: None,
+            grid: Some(g),
+            grid_style: Some(Box::new(style)),
+            progress: None,
+            finished: false,
+        }
+    }
+
+    pub fn apply(&mut self, update: AppDisplayState) {
+        if update.part_1_result != PartStatus::Pending {
+            self.part_1_result = update.part_1_result
+        }
+        if update.part_2_result != PartStatus::Pending {
+            self.part_2_result = update.part_2_result
+        }
+        if update.part_1_duration.is_some() {
+            self.part_1_duration = update.part_1_duration;
+        }
+        if update.part_2_duration.is_some() {
+            self.part_2_duration = update.part_2_duration;
+        }
+        if update.grid.is_some() {
+            self.grid = update.grid;
+        }
+        if update.grid_style.is_some() {
+            self.grid_style = update.grid_style;
+        }
+        if update.progress.is_some() {
+            self.progress = update.progress;
+        }
+        if update.finished {
+            self.finished = true;
         }
     }
 }
 
-pub fn run(rx: mpsc::Receiver<Event>) -> crate::Result<()> {
-    let mut terminal = ratatui::init_with_options(TerminalOptions {
-        viewport: Viewport::Inline(150),
-    });
+pub fn run_headless(rx: mpsc::Receiver<Event>) -> AppDisplayState {
+    let mut app_display_state = AppDisplayState::default();
+
+    for event in rx {
+        match event {
+            Event::UpdateAppDisplayState(ads) => app_display_state.apply(ads),
+            Event::NewRowEvent(_) | Event::Input(_) | Event::Tick | Event::Resize => {}
+        }
+    }
+
+    app_display_state
+}
+
+/// How quickly events from the solver are played back on the display
+#[derive(Debug, Default, PartialEq)]
+struct Playback {
+    paused: bool,
+    /// Playback runs at 2^speed times the normal rate
+    speed: i32,
+}
+
+impl Playback {
+    const NORMAL_ROW_DELAY: Duration = Duration::from_millis(5);
+    const SLOWEST: i32 = -3;
+    const FASTEST: i32 = 4;
+
+    fn faster(&mut self) {
+        self.speed = (self.speed + 1).min(Self::FASTEST);
+    }
+
+    fn slower(&mut self) {
+        self.speed = (self.speed - 1).max(Self::SLOWEST);
+    }
+
+    /// How long to wait after showing a new row before playing the next event
+    fn row_delay(&self) -> Duration {
+        if self.speed >= 0 {
+            Self::NORMAL_ROW_DELAY / (1 << self.speed)
+        } else {
+            Self::NORMAL_ROW_DELAY * (1 << -self.speed)
+        }
+    }
+}
+
+impl fmt::Display for Playback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.speed >= 0 {
+            write!(f, "Speed: {}x", 1 << self.speed)?;
+        } else {
+            write!(f, "Speed: 1/{}x", 1 << -self.speed)?;
+        }
+
+        if self.paused {
+            write!(f, " (paused)")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct DisplayOptions {
+    /// How many logged rows to keep before dropping the oldest
+    pub log_capacity: usize,
+    /// Take over the whole terminal on the alternate screen instead of drawing below the prompt
+    pub fullscreen: bool,
+    /// Whether the problem shows a grid or a log, which need more room than the answers
+    pub visualised: bool,
+}
+
+/// Sets up the terminal for [`run`], including the panic hook that puts it back afterwards
+pub fn init(options: &DisplayOptions) -> crate::Result<DefaultTerminal> {
+    if options.fullscreen {
+        Ok(ratatui::init())
+    } else {
+        let (_, terminal_height) = crossterm::terminal::size()?;
+
+        Ok(ratatui::init_with_options(TerminalOptions {
+            viewport: Viewport::Inline(inline_height(terminal_height, options.visualised)),
+        }))
+    }
+}
 
-    let mut redraw = true;
+/// How tall to make an inline viewport, which can't grow once it's been made. Problems that
+/// show a grid or a log get all the room there is, since the grid's size isn't known until it's
+/// sent, and the rest get the footer with a few rows of log underneath
+fn inline_height(terminal_height: u16, visualised: bool) -> u16 {
+    const LOG_ROWS: u16 = 6;
+
+    if visualised {
+        terminal_height
+    } else {
+        (FOOTER_HEIGHT + LOG_ROWS).min(terminal_height)
+    }
+}
 
-    let mut app_display_state = AppDisplayState {
-        part_1_result: None,
-        part_2_result: None,
-        rows: VecDeque::new(),
-        grid: None
-    };
+pub fn run<B: Backend>(
+    mut terminal: Terminal<B>,
+    rx: mpsc::Receiver<Event>,
+    options: &DisplayOptions,
+) -> crate::Result<()> {
+    let mut display = Display::new(options);
 
     loop {
-        if redraw {
-            terminal.draw(|frame| draw(frame, &app_display_state))?;
+        terminal.draw(|frame| display.draw(frame))?;
+
+        // Wait for the next thing to happen, then take everything else that's already been sent,
+        // so that keys are handled straight away rather than after every solver event before them
+        let first = match display.wait_time(Instant::now()) {
+            None => Some(rx.recv()?),
+            Some(timeout) => match rx.recv_timeout(timeout) {
+                Ok(event) => Some(event),
+                Err(mpsc::RecvTimeoutError::Timeout) => None,
+                Err(e) => return Err(e.into()),
+            },
+        };
+        for event in first.into_iter().chain(rx.try_iter()) {
+            display.receive(event);
         }
-        redraw = true;
 
-        match rx.recv()? {
-            Event::Input(event) => {
-                if event.code == event::KeyCode::Char('q') {
-                    break;
-                }
-            }
-            Event::Tick => {}
-            Event::UpdateAppDisplayState(ads) => {
-                if ads.part_1_result.is_some() {
-                    app_display_state.part_1_result = ads.part_1_result
-                }
-                if ads.part_2_result.is_some() {
-                    app_display_state.part_2_result = ads.part_2_result
-                }
-                if ads.grid.is_some() {
-                    app_display_state.grid = ads.grid;
+        if display.quit {
+            return Ok(());
+        }
+
+        display.play_due(Instant::now());
+    }
+}
+
+/// What the display is showing, and the solver events it has yet to play back
+struct Display {
+    app_display_state: AppDisplayState,
+    log: LogPane,
+    grid_scroll: GridScroll,
+    playback: Playback,
+    /// Events from the solver wait here until playback gets to them, so that input is still
+    /// handled while paused
+    queued: VecDeque<Event>,
+    next_event_at: Instant,
+    /// Paging moves by however many rows the log pane showed last time it was drawn
+    log_height: usize,
+    quit: bool,
+}
+
+impl Display {
+    fn new(options: &DisplayOptions) -> Self {
+        Display {
+            app_display_state: AppDisplayState::default(),
+            log: LogPane::new(options.log_capacity),
+            grid_scroll: GridScroll::default(),
+            playback: Playback::default(),
+            queued: VecDeque::new(),
+            next_event_at: Instant::now(),
+            log_height: 1,
+            quit: false,
+        }
+    }
+
+    /// Acts on input straight away and queues anything from the solver for playback
+    fn receive(&mut self, event: Event) {
+        match event {
+            Event::Input(key) => self.handle_key(key.code),
+            // Drawing picks up the new terminal size
+            Event::Tick | Event::Resize => {}
+            event => self.queued.push_back(event),
+        }
+    }
+
+    fn handle_key(&mut self, code: event::KeyCode) {
+        match code {
+            event::KeyCode::Char('q') => self.quit = true,
+            event::KeyCode::Char(' ') => self.playback.paused = !self.playback.paused,
+            event::KeyCode::Char('n') if self.playback.paused => {
+                if let Some(event) = self.queued.pop_front() {
+                    self.play(event);
                 }
             }
+            event::KeyCode::Char('+') | event::KeyCode::Char('=') => self.playback.faster(),
+            event::KeyCode::Char('-') => self.playback.slower(),
+            event::KeyCode::Char('f') => self.log.toggle_follow(),
+            event::KeyCode::Up => self.log.scroll_up(1),
+            event::KeyCode::Down => self.log.scroll_down(1),
+            event::KeyCode::PageUp => self.log.scroll_up(self.log_height.max(1)),
+            event::KeyCode::PageDown => self.log.scroll_down(self.log_height.max(1)),
+            event::KeyCode::Char('h') => self.grid_scroll.pan(0, -1),
+            event::KeyCode::Char('j') => self.grid_scroll.pan(1, 0),
+            event::KeyCode::Char('k') => self.grid_scroll.pan(-1, 0),
+            event::KeyCode::Char('l') => self.grid_scroll.pan(0, 1),
+            _ => {}
+        }
+    }
+
+    /// How long to wait for more events before the next queued one is due, or `None` to wait
+    /// until something is sent
+    fn wait_time(&self, now: Instant) -> Option<Duration> {
+        if self.playback.paused || self.queued.is_empty() {
+            None
+        } else {
+            Some(self.next_event_at.saturating_duration_since(now))
+        }
+    }
+
+    /// Plays the next queued event if playback isn't paused and it's time for it
+    fn play_due(&mut self, now: Instant) {
+        if self.playback.paused || now < self.next_event_at {
+            return;
+        }
+
+        if let Some(event) = self.queued.pop_front() {
+            let delay = self.play(event);
+            self.next_event_at = now + delay;
+        }
+    }
+
+    /// Shows one event from the solver, returning how long to wait before the next one
+    fn play(&mut self, event: Event) -> Duration {
+        match event {
+            Event::UpdateAppDisplayState(ads) => self.app_display_state.apply(ads),
             Event::NewRowEvent(line) => {
-                app_display_state.rows.push_front(line);
-                sleep(Duration::from_millis(5));
+                self.log.push(line);
+                return self.playback.row_delay();
             }
+            Event::Input(_) | Event::Tick | Event::Resize => {}
         }
+
+        Duration::ZERO
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        self.log_height = draw(
+            frame,
+            &self.app_display_state,
+            &self.log,
+            &mut self.grid_scroll,
+            &self.playback,
+        );
+    }
+}
+
+/// Returns how many rows of the log were on screen
+fn draw(
+    frame: &mut Frame,
+    app_display_state: &AppDisplayState,
+    log: &LogPane,
+    grid_scroll: &mut GridScroll,
+    playback: &Playback,
+) -> usize {
+    let footer = footer(app_display_state, log, playback);
+    let grid_size = app_display_state.grid.as_ref().map(|g| {
+        (
+            u16::try_from(g.row_count).unwrap_or(u16::MAX),
+            u16::try_from(g.col_count).unwrap_or(u16::MAX),
+        )
+    });
+    let panes = Panes::new(frame.area(), grid_size, footer.height() as u16);
+
+    if let Some(g) = &app_display_state.grid {
+        frame.render_stateful_widget(
+            GridView::new(g).style(app_display_state.grid_style.as_deref()),
+            panes.grid,
+            grid_scroll,
+        );
     }
 
-    Ok(())
+    let log_height = panes.log.height as usize;
+    frame.render_widget(List::new(log.visible(log_height).cloned()), panes.log);
+
+    frame.render_widget(Paragraph::new(footer), panes.footer);
+
+    log_height
 }
 
-fn draw(frame: &mut Frame, app_display_state: &AppDisplayState) {
-    match &app_display_state.grid {
-        Some(g) => {
-            let areas = Layout::vertical([Constraint::from(140), Constraint::from(2)]).split(frame.area());
+/// Where each pane is drawn. The grid gets the room it needs, beside the log if the terminal is
+/// wide enough and above it if not, the footer gets its lines and the log has whatever is left
+#[derive(Debug, PartialEq)]
+struct Panes {
+    grid: Rect,
+    log: Rect,
+    footer: Rect,
+}
+
+impl Panes {
+    /// The narrowest the log can be before it goes under the grid instead
+    const MIN_LOG_WIDTH: u16 = 40;
 
-            frame.render_widget(
-                Paragraph::new(format!("{g:?}")),
-                areas[0]
-            );
+    /// `grid_size` is in rows and columns
+    fn new(area: Rect, grid_size: Option<(u16, u16)>, footer_height: u16) -> Self {
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(footer_height)]).areas(area);
 
-            frame.render_widget(
-                Paragraph::new(format!(
-                    "\
-            Part 1: {}\n\
-            Part 2: {}",
-                    app_display_state.part_1_result.unwrap_or(0),
-                    app_display_state.part_2_result.unwrap_or(0)
-                )),
-                areas[1],
-            );
+        let [grid, log] = match grid_size {
+            None => [Rect::new(main.x, main.y, 0, 0), main],
+            Some((_, cols)) if main.width >= cols.saturating_add(Self::MIN_LOG_WIDTH) => {
+                Layout::horizontal([Constraint::Length(cols), Constraint::Min(0)])
+                    .spacing(1)
+                    .areas(main)
+            }
+            Some((rows, _)) => {
+                Layout::vertical([Constraint::Length(rows), Constraint::Min(0)]).areas(main)
+            }
+        };
+
+        Panes { grid, log, footer }
+    }
+}
+
+/// The most lines [`footer`] takes
+const FOOTER_HEIGHT: u16 = 5;
+
+fn footer(app_display_state: &AppDisplayState, log: &LogPane, playback: &Playback) -> Text<'static> {
+    let mut text = format!(
+        "\
+        {}  {playback}  {}\n\
+        Part 1: {}\n\
+        Part 2: {}",
+        if app_display_state.finished { "Done" } else { "Solving…" },
+        log.status(),
+        app_display_state.part_1_result,
+        app_display_state.part_2_result
+    );
+
+    if let Some(progress) = app_display_state.progress {
+        text.push_str(&format!("\nProgress: {:.0}%", progress * 100.0));
+    }
+
+    text.push_str("\nq quit  space pause  n step  +/- speed  ↑/↓/PgUp/PgDn scroll  f follow");
+    if app_display_state.grid.is_some() {
+        text.push_str("  h/j/k/l pan");
+    }
+
+    Text::from(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::text::Line;
+
+    #[test]
+    fn should_scale_row_delay_with_speed_within_limits() {
+        let mut playback = Playback::default();
+        assert_eq!(playback.row_delay(), Duration::from_millis(5));
+
+        playback.slower();
+        assert_eq!(playback.row_delay(), Duration::from_millis(10));
+        assert_eq!(playback.to_string(), "Speed: 1/2x");
+
+        for _ in 0..10 {
+            playback.faster();
         }
-        None => {
-            let areas = Layout::vertical([Constraint::from(6), Constraint::from(2)]).split(frame.area());
+        playback.paused = true;
+        assert_eq!(playback.row_delay(), Duration::from_micros(312) + Duration::from_nanos(500));
+        assert_eq!(playback.to_string(), "Speed: 16x (paused)");
+    }
 
-            frame.render_widget(
-                List::new(app_display_state.rows.clone()),
-                areas[0]
-            );
+    #[test]
+    fn should_put_grid_beside_log_only_when_there_is_room() {
+        let wide = Panes::new(Rect::new(0, 0, 100, 30), Some((10, 50)), 5);
+        assert_eq!(wide.grid, Rect::new(0, 0, 50, 25));
+        assert_eq!(wide.log, Rect::new(51, 0, 49, 25));
+        assert_eq!(wide.footer, Rect::new(0, 25, 100, 5));
 
-            frame.render_widget(
-                Paragraph::new(format!(
-                    "\
-            Part 1: {}\n\
-            Part 2: {}",
-                    app_display_state.part_1_result.unwrap_or(0),
-                    app_display_state.part_2_result.unwrap_or(0)
-                )),
-                areas[1],
-            );
+        let narrow = Panes::new(Rect::new(0, 0, 60, 30), Some((10, 50)), 5);
+        assert_eq!(narrow.grid, Rect::new(0, 0, 60, 10));
+        assert_eq!(narrow.log, Rect::new(0, 10, 60, 15));
+    }
+
+    #[test]
+    fn should_only_give_visualised_problems_the_whole_terminal() {
+        assert_eq!(inline_height(50, true), 50);
+        assert_eq!(inline_height(50, false), 11);
+        assert_eq!(inline_height(8, false), 8);
+    }
+
+    fn options() -> DisplayOptions {
+        DisplayOptions {
+            log_capacity: 10,
+            fullscreen: false,
+            visualised: true,
         }
     }
 
+    fn key(c: char) -> Event {
+        Event::Input(event::KeyEvent::from(event::KeyCode::Char(c)))
+    }
+
+    #[test]
+    fn should_handle_keys_before_solver_events_sent_ahead_of_them() {
+        let mut display = Display::new(&options());
+        for row in ["a", "b", "c"] {
+            display.receive(Event::NewRowEvent(Line::from(row)));
+        }
+
+        display.receive(key(' '));
+        display.play_due(Instant::now());
+        assert!(display.playback.paused);
+        assert_eq!(display.log.visible(10).count(), 0);
+
+        display.receive(key('n'));
+        assert_eq!(display.log.visible(10).count(), 1);
+        assert_eq!(display.queued.len(), 2);
+    }
+
+    #[test]
+    fn should_quit_without_playing_solver_events_sent_before_the_key() {
+        let (tx, rx) = mpsc::channel();
+        for i in 0..1000 {
+            tx.send(Event::NewRowEvent(Line::from(i.to_string()))).unwrap();
+        }
+        tx.send(key('q')).unwrap();
+
+        let terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        let started = Instant::now();
+
+        // The sender is still alive, so this can only return because of the key. Playing every
+        // row first would take 5 seconds
+        run(terminal, rx, &options()).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
 }
@@ -0,0 +1,113 @@
+use crate::problems::common::Grid;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::StatefulWidget;
+use std::collections::{HashMap, HashSet};
+
+/// How a problem wants its grid drawn
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridStyle {
+    /// The style of every cell holding a character, e.g. obstacles, robots or region IDs. Other
+    /// characters are drawn plain
+    pub cells: HashMap<char, Style>,
+    /// Cells to pick out whatever they hold, as (row, column)
+    pub highlighted: HashSet<(i32, i32)>,
+}
+
+/// Which part of a grid too big for its pane is in view, as the row and column in the top left
+#[derive(Debug, Default, PartialEq)]
+pub struct GridScroll {
+    row: usize,
+    col: usize,
+}
+
+impl GridScroll {
+    /// Rendering stops the view going past the bottom or right of the grid
+    pub fn pan(&mut self, rows: isize, cols: isize) {
+        self.row = self.row.saturating_add_signed(rows);
+        self.col = self.col.saturating_add_signed(cols);
+    }
+}
+
+pub struct GridView<'a> {
+    grid: &'a Grid<char>,
+    style: Option<&'a GridStyle>,
+}
+
+impl<'a> GridView<'a> {
+    pub fn new(grid: &'a Grid<char>) -> Self {
+        GridView { grid, style: None }
+    }
+
+    pub fn style(mut self, style: Option<&'a GridStyle>) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn cell_style(&self, row: usize, col: usize, c: char) -> Style {
+        let Some(style) = self.style else {
+            return Style::default();
+        };
+
+        let cell_style = style.cells.get(&c).copied().unwrap_or_default();
+
+        if style.highlighted.contains(&(row as i32, col as i32)) {
+            cell_style.add_modifier(Modifier::REVERSED)
+        } else {
+            cell_style
+        }
+    }
+}
+
+impl StatefulWidget for GridView<'_> {
+    type State = GridScroll;
+
+    fn render(self, area: Rect, buf: &mut Buffer, scroll: &mut GridScroll) {
+        let (height, width) = (area.height as usize, area.width as usize);
+
+        scroll.row = scroll.row.min(self.grid.row_count.saturating_sub(height));
+        scroll.col = scroll.col.min(self.grid.col_count.saturating_sub(width));
+
+        for (y, line) in self.grid.lines.iter().skip(scroll.row).take(height).enumerate() {
+            for (x, c) in line.iter().skip(scroll.col).take(width).enumerate() {
+                let style = self.cell_style(scroll.row + y, scroll.col + x, *c);
+
+                if let Some(cell) = buf.cell_mut((area.x + x as u16, area.y + y as u16)) {
+                    cell.set_char(*c).set_style(style);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn should_draw_the_scrolled_part_of_the_grid_with_its_styles() {
+        let grid = Grid::from_string("#...\n.#..\n..#.\n...#").unwrap();
+        let style = GridStyle {
+            cells: HashMap::from([('#', Style::new().fg(Color::Red))]),
+            highlighted: HashSet::from([(3, 2)]),
+        };
+        let mut scroll = GridScroll::default();
+        scroll.pan(10, 1);
+
+        let area = Rect::new(0, 0, 2, 2);
+        let mut buf = Buffer::empty(area);
+        GridView::new(&grid)
+            .style(Some(&style))
+            .render(area, &mut buf, &mut scroll);
+
+        // Panning past the bottom stops at the last rows that fill the pane
+        assert_eq!(scroll, GridScroll { row: 2, col: 1 });
+
+        let mut expected = Buffer::with_lines([".#", ".."]);
+        expected[(1, 0)].set_style(Style::new().fg(Color::Red));
+        expected[(1, 1)].set_style(Style::new().add_modifier(Modifier::REVERSED));
+        assert_eq!(buf, expected);
+    }
+}
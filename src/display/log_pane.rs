@@ -0,0 +1,116 @@
+use ratatui::text::Line;
+use std::collections::VecDeque;
+
+pub const DEFAULT_LOG_CAPACITY: usize = 1000;
+
+/// The lines a problem has logged, newest first, keeping at most `capacity` of them
+#[derive(Debug)]
+pub struct LogPane {
+    rows: VecDeque<Line<'static>>,
+    capacity: usize,
+    /// How many of the newest rows are scrolled out of view
+    offset: usize,
+    /// Whether the view stays on the newest rows as more arrive
+    follow: bool,
+}
+
+impl LogPane {
+    pub fn new(capacity: usize) -> Self {
+        LogPane {
+            rows: VecDeque::new(),
+            capacity,
+            offset: 0,
+            follow: true,
+        }
+    }
+
+    pub fn push(&mut self, line: Line<'static>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.rows.push_front(line);
+        if self.rows.len() > self.capacity {
+            self.rows.pop_back();
+        }
+
+        // Keep the same rows in view while the user is looking back through the log
+        if !self.follow {
+            self.offset = (self.offset + 1).min(self.last_offset());
+        }
+    }
+
+    /// Moves the view towards older rows, which stops it following new ones
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.offset = (self.offset + rows).min(self.last_offset());
+        self.follow = false;
+    }
+
+    /// Moves the view towards newer rows, which stops it following new ones
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_sub(rows);
+        self.follow = false;
+    }
+
+    /// Turning follow on jumps back to the newest row
+    pub fn toggle_follow(&mut self) {
+        self.follow = !self.follow;
+        if self.follow {
+            self.offset = 0;
+        }
+    }
+
+    /// The rows that fit in a pane `height` rows tall
+    pub fn visible(&self, height: usize) -> impl Iterator<Item = &Line<'static>> {
+        self.rows.iter().skip(self.offset).take(height)
+    }
+
+    pub fn status(&self) -> String {
+        if self.follow {
+            String::from("Log: following")
+        } else {
+            format!("Log: {}/{}", self.offset + 1, self.rows.len())
+        }
+    }
+
+    fn last_offset(&self) -> usize {
+        self.rows.len().saturating_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visible_text(log: &LogPane, height: usize) -> Vec<String> {
+        log.visible(height).map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn should_drop_oldest_rows_beyond_capacity() {
+        let mut log = LogPane::new(2);
+        for row in ["a", "b", "c"] {
+            log.push(Line::from(row));
+        }
+
+        assert_eq!(visible_text(&log, 5), ["c", "b"]);
+    }
+
+    #[test]
+    fn should_keep_the_view_still_when_not_following() {
+        let mut log = LogPane::new(10);
+        for row in ["a", "b", "c"] {
+            log.push(Line::from(row));
+        }
+
+        log.scroll_down(1);
+        assert_eq!(visible_text(&log, 2), ["b", "a"]);
+
+        log.push(Line::from("d"));
+        assert_eq!(visible_text(&log, 2), ["b", "a"]);
+        assert_eq!(log.status(), "Log: 3/4");
+
+        log.toggle_follow();
+        assert_eq!(visible_text(&log, 2), ["d", "c"]);
+    }
+}
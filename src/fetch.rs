@@ -0,0 +1,336 @@
+use crate::client::{AocClient, ClientConfig};
+use crate::puzzle::Puzzle;
+use crate::schedule;
+use crate::schedule::Clock;
+use crate::{days_in_year, has_input, input_path, session_token, MAX_PROBLEM};
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayRange(RangeInclusive<u32>);
+
+impl DayRange {
+    pub fn all(year: i32) -> DayRange {
+        DayRange(1..=days_in_year(year))
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| match day.trim().parse::<u32>() {
+            Ok(d) if (1..=MAX_PROBLEM).contains(&d) => Ok(d),
+            _ => Err(format!("{day:?} is not a day between 1 and {MAX_PROBLEM}")),
+        };
+
+        let (start, end) = match s.split_once("..") {
+            Some((start, end)) => (
+                parse_day(start)?,
+                parse_day(end.strip_prefix('=').unwrap_or(end))?,
+            ),
+            None => (parse_day(s)?, parse_day(s)?),
+        };
+
+        if start > end {
+            Err(format!("{s:?} is an empty range"))
+        } else {
+            Ok(DayRange(start..=end))
+        }
+    }
+}
+
+enum FetchOutcome {
+    Fetched,
+    AlreadyPresent,
+    NotYetAvailable(DateTime<Utc>),
+    Failed(String),
+}
+
+impl fmt::Display for FetchOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchOutcome::Fetched => write!(f, "fetched"),
+            FetchOutcome::AlreadyPresent => write!(f, "already present, skipped"),
+            FetchOutcome::NotYetAvailable(ready_time) => {
+                write!(f, "not yet available, will be ready at {ready_time}")
+            }
+            FetchOutcome::Failed(reason) => write!(f, "failed: {reason}"),
+        }
+    }
+}
+
+pub fn fetch_data(
+    year: i32,
+    days: DayRange,
+    force: bool,
+    puzzle: bool,
+    config: ClientConfig,
+    clock: &dyn Clock,
+) -> crate::Result<()> {
+    if *days.0.end() > days_in_year(year) {
+        return Err(format!("{year} only has {} days", days_in_year(year)).into());
+    }
+    let client = AocClient::new(config, &session_token()?)?;
+
+    let failures = days
+        .0
+        .filter(|&problem_number| {
+            let outcome = fetch_day(&client, year, problem_number, force, puzzle, clock.now());
+            println!("Day {problem_number}: {outcome}");
+
+            matches!(outcome, FetchOutcome::Failed(_))
+        })
+        .count();
+
+    if failures > 0 {
+        Err(format!("Failed to fetch {failures} day(s)").into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn wait_and_fetch(
+    year: i32,
+    puzzle: bool,
+    config: ClientConfig,
+    clock: &dyn Clock,
+) -> crate::Result<()> {
+    let problem_number = schedule::next_unreleased_day(year, clock.now())
+        .ok_or("Every puzzle has already been released")?;
+    let client = AocClient::new(config, &session_token()?)?;
+
+    schedule::wait_until(clock, schedule::unlock_time(year, problem_number), |remaining| {
+        print!(
+            "\rDay {problem_number} unlocks in {}",
+            schedule::format_countdown(remaining)
+        );
+        let _ = io::stdout().flush();
+    });
+    println!();
+
+    match fetch_day(&client, year, problem_number, false, puzzle, clock.now()) {
+        FetchOutcome::Failed(reason) => {
+            Err(format!("Failed to fetch day {problem_number}: {reason}").into())
+        }
+        outcome => {
+            println!("Day {problem_number}: {outcome}");
+            Ok(())
+        }
+    }
+}
+
+fn fetch_day(
+    client: &AocClient,
+    year: i32,
+    problem_number: u32,
+    force: bool,
+    puzzle: bool,
+    now: DateTime<Utc>,
+) -> FetchOutcome {
+    let puzzle_directory = format!("sample_data/{year}/{problem_number}");
+    let path = if puzzle {
+        Path::new(&puzzle_directory).join("description.txt")
+    } else {
+        input_path(year, problem_number as usize)
+    };
+    let already_present = if puzzle {
+        path.exists()
+    } else {
+        has_input(year, problem_number as usize)
+    };
+    if !force && already_present {
+        return FetchOutcome::AlreadyPresent;
+    }
+
+    let problem_ready_time = schedule::unlock_time(year, problem_number);
+    if now < problem_ready_time {
+        return FetchOutcome::NotYetAvailable(problem_ready_time);
+    }
+
+    let result = if puzzle {
+        download_puzzle(client, year, problem_number)
+            .and_then(|puzzle| Ok(puzzle.save(Path::new(&puzzle_directory))?))
+    } else {
+        download_input(client, year, problem_number)
+            .and_then(|input| Ok(write_atomically(&path, &input)?))
+    };
+
+    match result {
+        Ok(()) => FetchOutcome::Fetched,
+        Err(e) => FetchOutcome::Failed(e.to_string()),
+    }
+}
+
+fn download_input(client: &AocClient, year: i32, problem_number: u32) -> crate::Result<String> {
+    let body = get_text(client, &format!("/{year}/day/{problem_number}/input"))?;
+
+    validate_input(&body)?;
+
+    Ok(body)
+}
+
+fn download_puzzle(client: &AocClient, year: i32, problem_number: u32) -> crate::Result<Puzzle> {
+    let page = get_text(client, &format!("/{year}/day/{problem_number}"))?;
+
+    Puzzle::parse(&page).ok_or("page did not contain a puzzle description".into())
+}
+
+fn get_text(client: &AocClient, path: &str) -> crate::Result<String> {
+    let response = client.get(path)?;
+
+    let status = response.status();
+    let body = response.text()?;
+
+    if !status.is_success() {
+        return Err(format!("server responded with {status}: {}", body.trim()).into());
+    }
+
+    Ok(body)
+}
+
+fn validate_input(body: &str) -> Result<(), String> {
+    let trimmed = body.trim_start();
+
+    if trimmed.is_empty() {
+        Err(String::from("response was empty"))
+    } else if trimmed.starts_with('<') || body.contains("Please log in") {
+        Err(String::from(
+            "response looks like a web page rather than puzzle input, check the session token",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Renaming within a directory replaces the destination in one step, so an interrupted write
+    // can only ever leave the temporary file behind
+    let temporary_path = path.with_extension("txt.tmp");
+    fs::write(&temporary_path, contents)?;
+    fs::rename(&temporary_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_config;
+    use crate::test_server::TestServer;
+
+    mod day_range {
+        use super::*;
+
+        #[test]
+        fn should_parse_single_day() {
+            assert_eq!("12".parse(), Ok(DayRange(12..=12)));
+        }
+
+        #[test]
+        fn should_parse_range_including_end() {
+            assert_eq!("10..14".parse(), Ok(DayRange(10..=14)));
+            assert_eq!("10..=14".parse(), Ok(DayRange(10..=14)));
+        }
+
+        #[test]
+        fn should_reject_days_outside_advent() {
+            assert!("0".parse::<DayRange>().is_err());
+            assert!("20..26".parse::<DayRange>().is_err());
+            assert!("x".parse::<DayRange>().is_err());
+        }
+
+        #[test]
+        fn should_cover_fewer_days_from_2025() {
+            assert_eq!(DayRange::all(2024), DayRange(1..=25));
+            assert_eq!(DayRange::all(2025), DayRange(1..=12));
+        }
+
+        #[test]
+        fn should_reject_backwards_range() {
+            assert!("14..10".parse::<DayRange>().is_err());
+        }
+    }
+
+    mod download_input {
+        use super::*;
+
+        fn client(server: &TestServer) -> AocClient {
+            AocClient::new(test_config(&server.base_url), "abc123").unwrap()
+        }
+
+        #[test]
+        fn should_return_body_with_session_cookie() {
+            let server = TestServer::start(vec![(200, "1 2\n3 4\n")]);
+
+            let input = download_input(&client(&server), 2024, 4).unwrap();
+
+            assert_eq!(input, "1 2\n3 4\n");
+            let requests = server.requests();
+            assert_eq!(requests[0].url, "/2024/day/4/input");
+            assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+        }
+
+        #[test]
+        fn should_fail_on_error_status() {
+            let server = TestServer::start(vec![(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            )]);
+
+            let error = download_input(&client(&server), 2024, 4).unwrap_err();
+
+            assert!(error.to_string().contains("400 Bad Request"));
+        }
+
+        #[test]
+        fn should_reject_html_page_served_as_input() {
+            let server = TestServer::start(vec![(
+                200,
+                "<!DOCTYPE html>\n<html><body>Please log in</body></html>",
+            )]);
+
+            assert!(download_input(&client(&server), 2024, 4).is_err());
+        }
+
+        #[test]
+        fn should_reject_empty_input() {
+            let server = TestServer::start(vec![(200, "")]);
+
+            assert!(download_input(&client(&server), 2024, 4).is_err());
+        }
+    }
+
+    #[test]
+    fn should_download_and_parse_puzzle_page() {
+        let server = TestServer::start(vec![(200, include_str!("fixtures/puzzle_page.html"))]);
+        let client = AocClient::new(test_config(&server.base_url), "abc123").unwrap();
+
+        let puzzle = download_puzzle(&client, 2024, 1).unwrap();
+
+        assert_eq!(puzzle.examples.len(), 2);
+        assert_eq!(server.requests()[0].url, "/2024/day/1");
+    }
+
+    #[test]
+    fn should_replace_existing_file_when_writing_atomically() {
+        let directory = std::env::temp_dir().join(format!("fetch-{}", std::process::id()));
+        let path = directory.join("3.txt");
+
+        write_atomically(&path, "old").unwrap();
+        write_atomically(&path, "new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(!path.with_extension("txt.tmp").exists());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,137 @@
+use crate::Part;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl DayAnswers {
+    fn slot(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AnswerLedger {
+    days: BTreeMap<usize, DayAnswers>,
+}
+
+impl Serialize for AnswerLedger {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // TOML table names have to be strings, but days should still be listed in numeric order
+        serializer.collect_map(
+            self.days
+                .iter()
+                .map(|(day, answers)| (day.to_string(), answers)),
+        )
+    }
+}
+
+impl AnswerLedger {
+    pub fn load(path: &Path) -> crate::Result<AnswerLedger> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => AnswerLedger::parse(&contents)
+                .map_err(|e| format!("Unable to parse {}: {e}", path.display()).into()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(AnswerLedger::default()),
+            Err(e) => Err(format!("Unable to read {}: {e}", path.display()).into()),
+        }
+    }
+
+    fn parse(contents: &str) -> crate::Result<AnswerLedger> {
+        let days = toml::from_str::<BTreeMap<String, DayAnswers>>(contents)?
+            .into_iter()
+            .map(|(day, answers)| match day.parse::<usize>() {
+                Ok(day) => Ok((day, answers)),
+                Err(_) => Err(format!("{day:?} is not a day number")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(AnswerLedger { days })
+    }
+
+    pub fn save(&self, path: &Path) -> crate::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, toml::to_string(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&str> {
+        self.days.get(&day).and_then(|answers| match part {
+            Part::One => answers.part1.as_deref(),
+            Part::Two => answers.part2.as_deref(),
+        })
+    }
+
+    pub fn record(&mut self, day: usize, part: Part, answer: &str) {
+        *self.days.entry(day).or_default().slot(part) = Some(answer.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_answers_keyed_by_day_and_part() {
+        let ledger = AnswerLedger::parse(
+            "\
+            [1]\n\
+            part1 = \"11\"\n\
+            part2 = \"31\"\n\
+            \n\
+            [5]\n\
+            part2 = \"123\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(ledger.get(1, Part::One), Some("11"));
+        assert_eq!(ledger.get(1, Part::Two), Some("31"));
+        assert_eq!(ledger.get(5, Part::One), None);
+        assert_eq!(ledger.get(5, Part::Two), Some("123"));
+        assert_eq!(ledger.get(6, Part::One), None);
+    }
+
+    #[test]
+    fn should_reject_tables_that_are_not_days() {
+        assert!(AnswerLedger::parse("[one]\npart1 = \"1\"\n").is_err());
+    }
+
+    #[test]
+    fn should_write_days_in_numeric_order() {
+        let mut ledger = AnswerLedger::default();
+        ledger.record(10, Part::One, "7");
+        ledger.record(2, Part::Two, "8");
+        ledger.record(2, Part::One, "9");
+
+        let serialized = toml::to_string(&ledger).unwrap();
+
+        assert_eq!(
+            serialized,
+            "\
+            [2]\n\
+            part1 = \"9\"\n\
+            part2 = \"8\"\n\
+            \n\
+            [10]\n\
+            part1 = \"7\"\n"
+        );
+        assert_eq!(AnswerLedger::parse(&serialized).unwrap(), ledger);
+    }
+}
@@ -1,95 +1,618 @@
-use crate::display::AppDisplayState;
-use crate::problems::get_all_problems;
-use chrono::{TimeZone, Utc};
+use crate::bench::Benchmark;
+use crate::client::AocClient;
+use crate::display::{AppDisplayState, DisplayOptions, PartStatus};
+use crate::problems::{problem_registry, AnyProblem, ProblemError, SolveContext};
+use crate::ledger::AnswerLedger;
+use crate::list::DayListing;
+use crate::runner::{PartOutcome, RowOutcome, SummaryRow};
+use crate::submit::{Submission, SubmissionLog, SubmissionOutcome};
+use clap::ValueEnum;
 use crossterm::event;
 use ratatui::text::Line;
-use std::collections::VecDeque;
+use serde::{Serialize, Serializer};
 use std::error::Error;
-use std::fs::File;
-use std::io::Write;
+use std::fmt;
+use std::io::Read;
+use std::path::PathBuf;
 use std::sync::mpsc;
+use std::thread;
 
 mod problems;
 
+mod answer;
+
+mod bench;
+
+mod client;
+
 mod display;
 
+mod fetch;
+
+mod ledger;
+
+mod list;
+
+mod output;
+
+mod puzzle;
+
+mod runner;
+
+mod schedule;
+
+mod submit;
+
+mod table;
+
+#[cfg(test)]
+mod test_server;
+
+pub const AOC_BASE_URL: &str = "https://adventofcode.com";
+
+pub use answer::Answer;
+pub use client::{ClientConfig, DEFAULT_USER_AGENT};
+pub use display::DEFAULT_LOG_CAPACITY;
+pub use fetch::{fetch_data, wait_and_fetch, DayRange};
+pub use output::OutputFormat;
+pub use schedule::{Clock, SystemClock};
+
+pub const DEFAULT_YEAR: i32 = 2024;
+
 const MAX_PROBLEM: u32 = 25;
 
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
-pub fn solve(problem_number: usize) -> Result<()> {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+/// Serialised as the part's number, as it's written everywhere else
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match self {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub fn days_in_year(year: i32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        MAX_PROBLEM
+    }
+}
+
+fn input_path(year: i32, day: usize) -> PathBuf {
+    PathBuf::from(format!("data/{year}/{day}.txt"))
+}
+
+/// Inputs used to be stored directly in data/ before there was more than one year
+fn legacy_input_path(day: usize) -> PathBuf {
+    PathBuf::from(format!("data/{day}.txt"))
+}
+
+fn load_input(year: i32, day: usize) -> std::io::Result<String> {
+    match std::fs::read_to_string(input_path(year, day)) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && year == DEFAULT_YEAR => {
+            std::fs::read_to_string(legacy_input_path(day))
+        }
+        result => result,
+    }
+}
+
+fn has_input(year: i32, day: usize) -> bool {
+    input_path(year, day).exists() || (year == DEFAULT_YEAR && legacy_input_path(day).exists())
+}
+
+fn ledger_path(year: i32) -> PathBuf {
+    PathBuf::from(format!("data/{year}/answers.toml"))
+}
+
+pub struct SolveOptions {
+    pub year: i32,
+    pub input: Option<String>,
+    pub sample: Option<usize>,
+    pub headless: bool,
+    pub part: Option<Part>,
+    pub format: OutputFormat,
+    pub log_capacity: usize,
+    pub fullscreen: bool,
+}
+
+pub fn solve(problem_number: usize, options: SolveOptions) -> Result<()> {
     let (tx, rx) = mpsc::channel();
 
-    let all_problems = get_all_problems(&tx);
-    if let Some(problem) = all_problems.get(problem_number - 1) {
-        let input = std::fs::read_to_string(format!("data/{problem_number}.txt")).unwrap();
+    let registry = problem_registry(options.year)?;
+    if let Some(problem) = registry.get(problem_number) {
+        let input = read_input(
+            options.year,
+            problem_number,
+            options.input.as_deref(),
+            options.sample,
+        )?;
+        let only_part = options.part;
+        let should_run = move |part: Part| only_part.is_none_or(|p| p == part);
+
+        if options.headless || options.format == OutputFormat::Json {
+            tx.send(Event::UpdateAppDisplayState(run_parts(problem, &input, should_run, None)))?;
+
+            // Every sender has to go before the receiver will stop waiting for more events
+            drop(tx);
+
+            return report_headless(problem_number, display::run_headless(rx), options.format);
+        }
+
+        let display_options = DisplayOptions {
+            log_capacity: options.log_capacity,
+            fullscreen: options.fullscreen,
+            visualised: problem.metadata().visualised,
+        };
+        let terminal = display::init(&display_options)?;
+
+        // Wrap the display's panic hook rather than the other way round, so that panics caught on
+        // the solver's thread stay quiet instead of putting the terminal back mid-solve, while a
+        // panic on any other thread still does
+        runner::install_panic_hook();
 
         display::input_handling(tx.clone());
 
-        let part_1_result = (**problem).part1(input.as_str());
-        let part_2_result = (**problem).part2(input.as_str());
+        // Solve on another thread so that the display shows events as they're sent. The thread
+        // isn't joined, so quitting mid-solve abandons it when the process exits
+        thread::spawn(move || {
+            if let Some(problem) = registry.get(problem_number) {
+                let state = run_parts(problem, &input, should_run, Some(&tx));
 
-        tx.send(Event::UpdateAppDisplayState(AppDisplayState {
-            part_1_result: Some(part_1_result),
-            part_2_result: Some(part_2_result),
-            rows: VecDeque::new(),
-            grid: None
-        })).unwrap();
-        // tx.send(Event::Input(event::KeyCode::Char('q').into())).unwrap();
+                // The display has gone if the user quit before solving finished
+                let _ = tx.send(Event::UpdateAppDisplayState(state));
+            }
+        });
 
-        let app_result = display::run(rx);
+        let app_result = display::run(terminal, rx, &display_options);
 
         ratatui::restore();
 
         app_result
     } else {
-        println!("No solution found for {}", problem_number);
+        Err(format!("No solution found for {} day {problem_number}", options.year).into())
+    }
+}
 
-        Ok(())
+fn read_input(
+    year: i32,
+    problem_number: usize,
+    input: Option<&str>,
+    sample: Option<usize>,
+) -> Result<String> {
+    if let Some(example) = sample {
+        let path = format!("sample_data/{year}/{problem_number}/example_{example}.txt");
+
+        return std::fs::read_to_string(&path).map_err(|e| {
+            format!("Unable to read example {path}: {e}. Run `fetch {problem_number} --puzzle` first").into()
+        });
+    }
+
+    match input {
+        Some("-") => {
+            let mut buffer = String::new();
+            std::io::stdin()
+                .read_to_string(&mut buffer)
+                .map_err(|e| format!("Unable to read input from stdin: {e}"))?;
+
+            Ok(buffer)
+        }
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| format!("Unable to read input file {path}: {e}").into()),
+        None => load_input(year, problem_number).map_err(|e| {
+            format!(
+                "Unable to read input file {}: {e}. Run `fetch` first or pass --input",
+                input_path(year, problem_number).display()
+            )
+            .into()
+        }),
     }
 }
 
-pub fn fetch_data() {
-    let session_token = std::fs::read_to_string("session_id_file.txt")
-        .or(std::env::var("SESSION_TOKEN"))
-        .unwrap();
-
-    for problem_number in 1..=MAX_PROBLEM {
-        let now = Utc::now();
-        let problem_ready_time = Utc
-            .with_ymd_and_hms(2024, 12, problem_number, 5, 0, 0)
-            .unwrap();
-        if now >= problem_ready_time {
-            println!("Fetching data for problem {problem_number}...");
-            if let Ok(body) = reqwest::blocking::Client::new()
-                .get(format!(
-                    "https://adventofcode.com/2024/day/{problem_number}/input"
-                ))
-                .header("Cookie", format!("session={session_token}"))
-                .send()
-            {
-                let t = body.text();
-                let file = File::create(format!("data/{problem_number}.txt"));
-
-                match (t, file) {
-                    (Ok(tt), Ok(mut f)) => {
-                        let _ = f.write_all(tt.as_bytes());
+fn run_parts(
+    problem: &dyn AnyProblem,
+    input: &str,
+    should_run: impl Fn(Part) -> bool,
+    tx: Option<&mpsc::Sender<Event>>,
+) -> AppDisplayState {
+    let [(part_1_result, part_1_duration), (part_2_result, part_2_duration)] =
+        runner::with_silenced_panics(|| {
+            let prepared = runner::run_parse(problem, input);
+
+            Part::ALL.map(|part| {
+                if !should_run(part) {
+                    return (PartStatus::NotRun, None);
+                }
+
+                let prepared = match &prepared {
+                    Ok((prepared, _)) => prepared,
+                    Err(e) => return (PartStatus::Failed(e.clone()), None),
+                };
+
+                let ctx = match tx {
+                    Some(tx) => SolveContext::displayed(part, tx),
+                    None => SolveContext::headless(part),
+                };
+
+                match runner::run_part(|| prepared.solve(&ctx)) {
+                    PartOutcome::Solved { answer, duration } => {
+                        (PartStatus::Solved(answer), Some(duration))
+                    }
+                    PartOutcome::Unimplemented => {
+                        (PartStatus::Failed(ProblemError::Unimplemented), None)
                     }
-                    (Err(e), _) => println!("Error in AOC Response: {e}"),
-                    (Ok(_), Err(e)) => println!("Error in file opening: {e}"),
+                    PartOutcome::Failed(e) => (PartStatus::Failed(e), None),
                 }
+            })
+        });
+
+    AppDisplayState {
+        part_1_result,
+        part_2_result,
+        part_1_duration,
+        part_2_duration,
+        finished: true,
+        ..AppDisplayState::default()
+    }
+}
+
+fn report_headless(
+    day: usize,
+    app_display_state: AppDisplayState,
+    format: OutputFormat,
+) -> Result<()> {
+    match format {
+        OutputFormat::Text => {
+            for (part, result) in Part::ALL.iter().zip([
+                &app_display_state.part_1_result,
+                &app_display_state.part_2_result,
+            ]) {
+                println!("Part {part}: {result}");
             }
-        } else {
-            println!(
-                "Data not yet available for problem {problem_number}. Current time is {now}, will be ready at {problem_ready_time}"
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&output::entries_from_state(day, &app_display_state))?
+        ),
+    }
+
+    let results = [
+        app_display_state.part_1_result,
+        app_display_state.part_2_result,
+    ];
+
+    if results
+        .iter()
+        .any(|result| matches!(result, PartStatus::Pending | PartStatus::Failed(_)))
+    {
+        Err("Not every part produced an answer".into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn solve_all(year: i32, format: OutputFormat) -> Result<()> {
+    let registry = problem_registry(year)?;
+    let rows = runner::with_silenced_panics(|| {
+        registry
+            .days()
+            .flat_map(|(day, problem)| {
+                let Some(problem) = problem else {
+                    return vec![SummaryRow {
+                        day,
+                        part: None,
+                        outcome: RowOutcome::MissingSolution,
+                    }];
+                };
+
+                let rows = match load_input(year, day) {
+                    Ok(input) => match runner::run_parse(problem, &input) {
+                        Ok((prepared, duration)) => [SummaryRow {
+                            day,
+                            part: None,
+                            outcome: RowOutcome::Parse(Ok(duration)),
+                        }]
+                        .into_iter()
+                        .chain(Part::ALL.map(|part| SummaryRow {
+                            day,
+                            part: Some(part),
+                            outcome: RowOutcome::Part(runner::run_part(|| {
+                                prepared.solve(&SolveContext::headless(part))
+                            })),
+                        }))
+                        .collect(),
+                        Err(e) => vec![SummaryRow {
+                            day,
+                            part: None,
+                            outcome: RowOutcome::Parse(Err(e)),
+                        }],
+                    },
+                    Err(_) => vec![SummaryRow {
+                        day,
+                        part: None,
+                        outcome: RowOutcome::MissingInput,
+                    }],
+                };
+
+                rows
+            })
+            .collect::<Vec<_>>()
+    });
+
+    match format {
+        OutputFormat::Text => println!("{}", runner::format_summary(&rows)),
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&output::entries_from_rows(&rows))?
+        ),
+    }
+
+    let failures = rows
+        .iter()
+        .filter(|row| {
+            matches!(
+                row.outcome,
+                RowOutcome::Part(PartOutcome::Failed(_))
+                    | RowOutcome::Parse(Err(ProblemError::Parse { .. } | ProblemError::Failed(_)))
             )
+        })
+        .count();
+
+    if failures > 0 {
+        Err(format!("{failures} part(s) failed").into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn list(year: i32) -> Result<()> {
+    let registry = problem_registry(year)?;
+    let ledger = AnswerLedger::load(&ledger_path(year))?;
+
+    let days = registry
+        .days()
+        .map(|(day, problem)| DayListing {
+            day,
+            metadata: problem.map(AnyProblem::metadata),
+            has_input: has_input(year, day),
+            verified: Part::ALL.map(|part| ledger.get(day, part).is_some()),
+        })
+        .collect::<Vec<_>>();
+
+    println!("{}", list::format_listing(&days));
+
+    Ok(())
+}
+
+pub fn bench(year: i32, day: Option<usize>, runs: usize, format: OutputFormat) -> Result<()> {
+    let registry = problem_registry(year)?;
+
+    let days = match day {
+        Some(day) => {
+            let problem = registry
+                .get(day)
+                .ok_or(format!("No solution found for {year} day {day}"))?;
+
+            vec![(day, problem)]
+        }
+        None => registry
+            .days()
+            .filter_map(|(day, problem)| problem.map(|problem| (day, problem)))
+            .filter(|&(day, _)| has_input(year, day))
+            .collect(),
+    };
+
+    let benchmark = Benchmark {
+        year,
+        runs,
+        days: days
+            .into_iter()
+            .map(|(day, problem)| {
+                let input = read_input(year, day, None, None)?;
+
+                Ok(bench::bench_day(day, problem, &input, runs))
+            })
+            .collect::<Result<Vec<_>>>()?,
+    };
+
+    match format {
+        OutputFormat::Text => println!("{}", bench::format_benchmark(&benchmark)),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&benchmark)?),
+    }
+
+    let failures = benchmark.days.iter().filter(|day| day.error.is_some()).count();
+
+    if failures > 0 {
+        Err(format!("{failures} day(s) failed").into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn submit(
+    year: i32,
+    day: usize,
+    part: Part,
+    answer: Option<String>,
+    config: ClientConfig,
+) -> Result<()> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => compute_answer(year, day, part)?,
+    };
+    let log_path = PathBuf::from(format!("data/{year}/submissions.tsv"));
+    let mut log = SubmissionLog::load(&log_path)?;
+
+    if let Some(reason) = log.check(day, part, &answer) {
+        return Err(format!("Not submitting: {reason}").into());
+    }
+
+    println!("Submitting {answer} for {year} day {day} part {part}...");
+    let client = AocClient::new(config, &session_token()?)?;
+    let outcome = submit::post_answer(&client, year, day, part, &answer)?;
+    println!("{outcome}");
+
+    log.record(
+        &log_path,
+        Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            outcome: outcome.clone(),
+        },
+    )?;
+
+    match outcome {
+        SubmissionOutcome::Correct => {
+            let mut ledger = AnswerLedger::load(&ledger_path(year))?;
+            ledger.record(day, part, &answer);
+            ledger.save(&ledger_path(year))
         }
+        _ => Err("Answer was not accepted".into()),
     }
 }
 
+pub fn verify(year: i32, lock: bool) -> Result<()> {
+    let ledger_path = ledger_path(year);
+    let mut ledger = AnswerLedger::load(&ledger_path)?;
+    let registry = problem_registry(year)?;
+
+    if ledger.is_empty() && !lock {
+        println!(
+            "No answers recorded in {} yet, run `verify --lock` to record them",
+            ledger_path.display()
+        );
+    }
+
+    let (failures, locked) = runner::with_silenced_panics(|| {
+        let mut failures = 0;
+        let mut locked = 0;
+
+        for (day, problem) in registry.days() {
+            let expected = Part::ALL.map(|part| ledger.get(day, part).map(str::to_string));
+
+            if !lock && expected.iter().all(Option::is_none) {
+                continue;
+            }
+
+            let Some(problem) = problem else {
+                if expected.iter().any(Option::is_some) {
+                    failures += 1;
+                    println!("Day {day}: answers are recorded but there is no solution for it");
+                }
+                continue;
+            };
+
+            let Ok(input) = load_input(year, day) else {
+                println!("Day {day}: no input, skipped");
+                continue;
+            };
+
+            let prepared = runner::run_parse(problem, &input);
+
+            for (part, expected) in Part::ALL.into_iter().zip(expected) {
+                if expected.is_none() && !lock {
+                    continue;
+                }
+
+                let outcome = match &prepared {
+                    Ok((prepared, _)) => {
+                        runner::run_part(|| prepared.solve(&SolveContext::headless(part)))
+                    }
+                    Err(ProblemError::Unimplemented) => PartOutcome::Unimplemented,
+                    Err(e) => PartOutcome::Failed(e.clone()),
+                };
+
+                match (outcome, expected) {
+                    (PartOutcome::Solved { answer, .. }, Some(expected)) => {
+                        if answer.to_string() == expected {
+                            println!("Day {day} part {part}: ok");
+                        } else {
+                            failures += 1;
+                            println!("Day {day} part {part}: MISMATCH, expected {expected} but got {answer}");
+                        }
+                    }
+                    (PartOutcome::Solved { answer, .. }, None) => {
+                        locked += 1;
+                        ledger.record(day, part, &answer.to_string());
+                        println!("Day {day} part {part}: locked in {answer}");
+                    }
+                    (PartOutcome::Unimplemented, Some(expected)) => {
+                        failures += 1;
+                        println!("Day {day} part {part}: expected {expected} but the part is not implemented");
+                    }
+                    (PartOutcome::Unimplemented, None) => {}
+                    (PartOutcome::Failed(e), _) => {
+                        failures += 1;
+                        println!("Day {day} part {part}: failed ({e})");
+                    }
+                }
+            }
+        }
+
+        (failures, locked)
+    });
+
+    if locked > 0 {
+        ledger.save(&ledger_path)?;
+    }
+
+    if failures > 0 {
+        Err(format!("{failures} part(s) did not match {}", ledger_path.display()).into())
+    } else {
+        Ok(())
+    }
+}
+
+fn compute_answer(year: i32, day: usize, part: Part) -> Result<String> {
+    let registry = problem_registry(year)?;
+    let problem = registry
+        .get(day)
+        .ok_or(format!("No solution found for {year} day {day}"))?;
+    let input = read_input(year, day, None, None)?;
+
+    println!("Solving day {day} part {part}...");
+    match runner::with_silenced_panics(|| runner::run_part(|| problem.solve(part, &input))) {
+        PartOutcome::Solved { answer, .. } => Ok(answer.to_string()),
+        PartOutcome::Unimplemented => Err(format!("Day {day} part {part} is not implemented").into()),
+        PartOutcome::Failed(e) => Err(format!("Day {day} part {part} failed: {e}").into()),
+    }
+}
+
+pub(crate) fn session_token() -> Result<String> {
+    std::fs::read_to_string("session_id_file.txt")
+        .or(std::env::var("SESSION_TOKEN"))
+        .map(|token| token.trim().to_string())
+        .map_err(|_| "No session token found in session_id_file.txt or SESSION_TOKEN".into())
+}
+
 pub enum Event {
     Tick,
     Input(event::KeyEvent),
+    Resize,
     UpdateAppDisplayState(AppDisplayState),
     NewRowEvent(Line<'static>)
 }
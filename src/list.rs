@@ -0,0 +1,124 @@
+use crate::problems::Metadata;
+use crate::table::{format_table, Align};
+use crate::Part;
+
+pub struct DayListing {
+    pub day: usize,
+    /// Only there when the day has a solution
+    pub metadata: Option<Metadata>,
+    pub has_input: bool,
+    /// Which parts have a correct answer recorded in the ledger
+    pub verified: [bool; 2],
+}
+
+pub fn format_listing(days: &[DayListing]) -> String {
+    let yes_no = |b: bool| String::from(if b { "yes" } else { "no" });
+
+    let cells = days
+        .iter()
+        .map(|listing| {
+            let verified = Part::ALL
+                .iter()
+                .zip(listing.verified)
+                .filter(|(_, verified)| *verified)
+                .map(|(part, _)| part.to_string())
+                .collect::<Vec<_>>();
+            let verified = if verified.is_empty() {
+                String::from("-")
+            } else {
+                verified.join(", ")
+            };
+
+            match &listing.metadata {
+                Some(metadata) => [
+                    listing.day.to_string(),
+                    metadata.title.to_string(),
+                    metadata
+                        .tags
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    metadata.runtime.to_string(),
+                    yes_no(metadata.visualised),
+                    yes_no(true),
+                    yes_no(listing.has_input),
+                    verified,
+                ],
+                None => [
+                    listing.day.to_string(),
+                    String::from("-"),
+                    String::from("-"),
+                    String::from("-"),
+                    String::from("-"),
+                    yes_no(false),
+                    yes_no(listing.has_input),
+                    verified,
+                ],
+            }
+        })
+        .collect::<Vec<_>>();
+
+    format_table(
+        [
+            "Day",
+            "Title",
+            "Tags",
+            "Runtime",
+            "Visualised",
+            "Solution",
+            "Input",
+            "Verified",
+        ],
+        [
+            Align::Right,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+        ],
+        &cells,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problems::{Runtime, Tag};
+
+    #[test]
+    fn should_list_every_day_with_its_status() {
+        let days = [
+            DayListing {
+                day: 6,
+                metadata: Some(Metadata {
+                    title: "Guard Gallivant",
+                    tags: &[Tag::Grid, Tag::Simulation],
+                    visualised: false,
+                    runtime: Runtime::Slow,
+                }),
+                has_input: true,
+                verified: [true, false],
+            },
+            DayListing {
+                day: 17,
+                metadata: None,
+                has_input: false,
+                verified: [false, false],
+            },
+        ];
+
+        assert_eq!(
+            format_listing(&days),
+            [
+                "Day  Title            Tags              Runtime  Visualised  Solution  Input  Verified",
+                "  6  Guard Gallivant  grid, simulation  slow     no          yes       yes    1",
+                " 17  -                -                 -        -           no        no     -",
+            ]
+            .join("\n")
+        );
+    }
+}
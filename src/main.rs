@@ -1,13 +1,114 @@
+use advent_of_code_2024::{
+    ClientConfig, DayRange, OutputFormat, Part, SolveOptions, SystemClock, AOC_BASE_URL,
+    DEFAULT_LOG_CAPACITY, DEFAULT_USER_AGENT, DEFAULT_YEAR,
+};
 use clap::{Parser, Subcommand as ClapSubcommand};
 use std::error::Error;
+use std::io::IsTerminal;
+use std::process::ExitCode;
 
 #[derive(Debug, ClapSubcommand)]
 enum Subcommand {
     #[command()]
-    Fetch,
+    Fetch {
+        /// A single day (`12`) or an inclusive range of days (`10..14`). Defaults to every day
+        days: Option<DayRange>,
+
+        /// Download inputs again even if they are already in data/<YEAR>/
+        #[arg(long)]
+        force: bool,
+
+        /// Download the puzzle description and its examples into sample_data/<YEAR>/<DAY>/
+        /// instead of the input
+        #[arg(long)]
+        puzzle: bool,
+
+        /// Wait for the next puzzle to unlock, then download it straight away
+        #[arg(long, conflicts_with_all = ["days", "force"])]
+        wait: bool,
+    },
+
+    #[command(arg_required_else_help = true)]
+    Solve {
+        #[arg(required_unless_present = "all")]
+        problem: Option<usize>,
+
+        /// Run every registered problem and print a summary table
+        #[arg(long, conflicts_with = "problem")]
+        all: bool,
+
+        /// Print the answers instead of starting the interactive display. Implied when stdout is
+        /// not a terminal
+        #[arg(long)]
+        headless: bool,
+
+        /// Read the puzzle input from this file instead of data/<YEAR>/<PROBLEM>.txt, or from
+        /// stdin if given `-`
+        #[arg(long, conflicts_with = "all")]
+        input: Option<String>,
+
+        /// Solve an example fetched with `fetch --puzzle` (the first one unless a number is given)
+        #[arg(
+            long,
+            num_args = 0..=1,
+            default_missing_value = "1",
+            conflicts_with_all = ["all", "input"]
+        )]
+        sample: Option<usize>,
+
+        /// Only run this part of the problem
+        #[arg(long, conflicts_with = "all")]
+        part: Option<Part>,
+
+        /// Print the results as text or as JSON. JSON implies --headless
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+
+        /// How many logged rows the display keeps before dropping the oldest
+        #[arg(long, default_value_t = DEFAULT_LOG_CAPACITY)]
+        log_capacity: usize,
+
+        /// Use the whole terminal for the display instead of drawing below the prompt
+        #[arg(long)]
+        fullscreen: bool,
+    },
 
     #[command(arg_required_else_help = true)]
-    Solve { problem: usize },
+    Submit {
+        day: usize,
+
+        part: Part,
+
+        /// Submit this answer instead of solving the problem against data/<YEAR>/<DAY>.txt
+        #[arg(long)]
+        answer: Option<String>,
+    },
+
+    /// Time parsing and each part over several runs, without the interactive display
+    #[command()]
+    Bench {
+        /// Only benchmark this day. Defaults to every day with a solution and an input
+        day: Option<usize>,
+
+        /// How many times to run each problem
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+
+        /// Print the results as text or as JSON, e.g. to compare against an earlier commit
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+
+    /// Show every day of the year, what it's about and how far along it is
+    #[command()]
+    List,
+
+    #[command()]
+    Verify {
+        /// Record answers for parts that aren't in data/<YEAR>/answers.toml yet
+        #[arg(long)]
+        lock: bool,
+    },
 }
 
 #[derive(Parser, Debug)]
@@ -16,15 +117,94 @@ struct Args {
     #[arg()]
     #[command(subcommand)]
     subcommand: Subcommand,
+
+    /// Which year's event to work on
+    #[arg(long, global = true, default_value_t = DEFAULT_YEAR)]
+    year: i32,
+
+    /// Server to talk to, e.g. a local stand-in for testing
+    #[arg(long, global = true, default_value = AOC_BASE_URL)]
+    base_url: String,
+
+    /// User-Agent sent to the server, ideally including contact details. Falls back to
+    /// AOC_USER_AGENT
+    #[arg(long, global = true)]
+    user_agent: Option<String>,
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
+fn main() -> ExitCode {
     let args = Args::parse();
+    let client_config = ClientConfig {
+        base_url: args.base_url,
+        user_agent: args
+            .user_agent
+            .or_else(|| std::env::var("AOC_USER_AGENT").ok())
+            .unwrap_or_else(|| String::from(DEFAULT_USER_AGENT)),
+        ..ClientConfig::default()
+    };
 
-    match args.subcommand {
-        Subcommand::Fetch => Ok(advent_of_code_2024::fetch_data()),
+    let result: Result<(), Box<dyn Error>> = match args.subcommand {
+        Subcommand::Fetch { puzzle, wait: true, .. } => {
+            advent_of_code_2024::wait_and_fetch(args.year, puzzle, client_config, &SystemClock)
+        }
+        Subcommand::Fetch {
+            days,
+            force,
+            puzzle,
+            wait: false,
+        } => advent_of_code_2024::fetch_data(
+            args.year,
+            days.unwrap_or_else(|| DayRange::all(args.year)),
+            force,
+            puzzle,
+            client_config,
+            &SystemClock,
+        ),
+        Subcommand::Solve {
+            problem: Some(problem_number),
+            headless,
+            input,
+            sample,
+            part,
+            format,
+            log_capacity,
+            fullscreen,
+            ..
+        } => advent_of_code_2024::solve(
+            problem_number,
+            SolveOptions {
+                year: args.year,
+                input,
+                sample,
+                headless: headless || !std::io::stdout().is_terminal(),
+                part,
+                format,
+                log_capacity,
+                fullscreen,
+            },
+        ),
         Subcommand::Solve {
-            problem: problem_number,
-        } => advent_of_code_2024::solve(problem_number),
+            problem: None,
+            format,
+            ..
+        } => advent_of_code_2024::solve_all(args.year, format),
+        Subcommand::Submit {
+            day,
+            part,
+            answer,
+        } => advent_of_code_2024::submit(args.year, day, part, answer, client_config),
+        Subcommand::Bench { day, runs, format } => {
+            advent_of_code_2024::bench(args.year, day, runs as usize, format)
+        }
+        Subcommand::List => advent_of_code_2024::list(args.year),
+        Subcommand::Verify { lock } => advent_of_code_2024::verify(args.year, lock),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
     }
 }
@@ -0,0 +1,133 @@
+use crate::display::{AppDisplayState, PartStatus};
+use crate::runner::{PartOutcome, RowOutcome, SummaryRow};
+use crate::Part;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// The outcome of one part, as printed by `--format json`. Every key is always present, with
+/// `null` for whatever doesn't apply
+#[derive(Debug, PartialEq, Serialize)]
+pub struct SolveEntry {
+    pub day: usize,
+    pub part: Part,
+    /// A string so that answers too big for a JSON number survive
+    pub answer: Option<String>,
+    pub duration_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl SolveEntry {
+    fn solved(day: usize, part: Part, answer: String, duration: Option<Duration>) -> Self {
+        SolveEntry {
+            day,
+            part,
+            answer: Some(answer),
+            duration_ns: duration.map(|d| d.as_nanos() as u64),
+            error: None,
+        }
+    }
+
+    fn failed(day: usize, part: Part, error: String) -> Self {
+        SolveEntry {
+            day,
+            part,
+            answer: None,
+            duration_ns: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// One entry for each part that was asked for
+pub fn entries_from_state(day: usize, app_display_state: &AppDisplayState) -> Vec<SolveEntry> {
+    [
+        (Part::One, &app_display_state.part_1_result, app_display_state.part_1_duration),
+        (Part::Two, &app_display_state.part_2_result, app_display_state.part_2_duration),
+    ]
+    .into_iter()
+    .filter_map(|(part, result, duration)| match result {
+        PartStatus::NotRun => None,
+        PartStatus::Pending => Some(SolveEntry::failed(day, part, String::from("no answer"))),
+        PartStatus::Solved(answer) => Some(SolveEntry::solved(day, part, answer.to_string(), duration)),
+        PartStatus::Failed(e) => Some(SolveEntry::failed(day, part, e.to_string())),
+    })
+    .collect()
+}
+
+/// One entry for each part of every day that was attempted, leaving out days without an input or
+/// a solution
+pub fn entries_from_rows(rows: &[SummaryRow]) -> Vec<SolveEntry> {
+    rows.iter()
+        .flat_map(|row| match (&row.outcome, row.part) {
+            (RowOutcome::Part(PartOutcome::Solved { answer, duration }), Some(part)) => {
+                vec![SolveEntry::solved(row.day, part, answer.to_string(), Some(*duration))]
+            }
+            (RowOutcome::Part(PartOutcome::Unimplemented), Some(part)) => {
+                vec![SolveEntry::failed(row.day, part, String::from("not implemented"))]
+            }
+            (RowOutcome::Part(PartOutcome::Failed(e)), Some(part)) => {
+                vec![SolveEntry::failed(row.day, part, e.to_string())]
+            }
+            (RowOutcome::Parse(Err(e)), _) => Part::ALL
+                .map(|part| SolveEntry::failed(row.day, part, e.to_string()))
+                .into(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problems::ProblemError;
+    use crate::Answer;
+
+    #[test]
+    fn should_leave_out_parts_that_were_not_run() {
+        let state = AppDisplayState {
+            part_1_result: PartStatus::NotRun,
+            part_2_result: PartStatus::Solved(Answer::from(42)),
+            part_2_duration: Some(Duration::from_micros(5)),
+            ..AppDisplayState::default()
+        };
+
+        assert_eq!(
+            serde_json::to_string(&entries_from_state(7, &state)).unwrap(),
+            r#"[{"day":7,"part":2,"answer":"42","duration_ns":5000,"error":null}]"#
+        );
+    }
+
+    #[test]
+    fn should_report_parse_failure_against_both_parts() {
+        let rows = [
+            SummaryRow {
+                day: 3,
+                part: None,
+                outcome: RowOutcome::Parse(Err(ProblemError::parse(1, 2, "expected a number"))),
+            },
+            SummaryRow {
+                day: 4,
+                part: None,
+                outcome: RowOutcome::MissingInput,
+            },
+        ];
+
+        let entries = entries_from_rows(&rows);
+
+        assert_eq!(
+            entries,
+            Vec::from(Part::ALL.map(|part| SolveEntry::failed(
+                3,
+                part,
+                String::from("parse error at line 1, column 2: expected a number")
+            )))
+        );
+    }
+}
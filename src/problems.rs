@@ -1,72 +1,159 @@
-use crate::problems::problem1::Problem1;
-use crate::problems::problem10::Problem10;
-use crate::problems::problem11::Problem11;
-use crate::problems::problem12::Problem12;
-use crate::problems::problem13::Problem13;
-use crate::problems::problem14::Problem14;
-use crate::problems::problem2::Problem2;
-use crate::problems::problem3::Problem3;
-use crate::problems::problem4::Problem4;
-use crate::problems::problem5::Problem5;
-use crate::problems::problem6::Problem6;
-use crate::problems::problem7::Problem7;
-use crate::problems::problem8::Problem8;
-use crate::problems::problem9::Problem9;
-use crate::Event;
-use std::sync::mpsc::Sender;
+use crate::{Answer, Part};
 
 pub mod common;
-pub mod problem1;
-pub mod problem10;
-pub mod problem11;
-pub mod problem12;
-pub mod problem13;
-pub mod problem14;
+mod context;
+mod error;
+mod metadata;
+mod registry;
+
+/// Declares each problem's module and registers it for its year, so adding a day only needs a
+/// line here. The day number comes from the problem itself through [`Problem::DAY`]
+macro_rules! problems {
+    ($($year:literal => [$($module:ident::$problem:ident),* $(,)?]),* $(,)?) => {
+        $($(pub mod $module;)*)*
+
+        fn registered_problems(year: i32) -> Vec<Box<dyn AnyProblem>> {
+            match year {
+                $($year => vec![$(Box::new($module::$problem {})),*],)*
+                _ => vec![],
+            }
+        }
+    };
+}
+
+problems! {
+    2024 => [
+        problem1::Problem1,
+        problem2::Problem2,
+        problem3::Problem3,
+        problem4::Problem4,
+        problem5::Problem5,
+        problem6::Problem6,
+        problem7::Problem7,
+        problem8::Problem8,
+        problem9::Problem9,
+        problem10::Problem10,
+        problem11::Problem11,
+        problem12::Problem12,
+        problem13::Problem13,
+        problem14::Problem14,
+    ],
+}
+
+// Not registered until they're solved
 pub mod problem15;
 pub mod problem16;
 pub mod problem17;
 pub mod problem18;
 pub mod problem19;
-pub mod problem2;
 pub mod problem20;
 pub mod problem21;
 pub mod problem22;
 pub mod problem23;
 pub mod problem24;
 pub mod problem25;
-pub mod problem3;
-pub mod problem4;
-pub mod problem5;
-pub mod problem6;
-pub mod problem7;
-pub mod problem8;
-pub mod problem9;
-
-pub trait Problem<T> {
-    fn part1(&self, _input: &str) -> T {
-        todo!()
+
+pub use context::SolveContext;
+pub use error::ProblemError;
+pub use metadata::{Metadata, Runtime, Tag};
+pub use registry::Registry;
+
+pub trait Problem {
+    /// The day of the puzzle this solves, which is what it's looked up by
+    const DAY: usize;
+
+    /// The puzzle's title, as it appears on the site
+    const TITLE: &'static str;
+
+    const TAGS: &'static [Tag] = &[];
+
+    /// Whether solving it shows more than the answers, e.g. a grid or a log
+    const VISUALISED: bool = false;
+
+    const RUNTIME: Runtime = Runtime::Fast;
+
+    type Parsed;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError>;
+
+    fn part1(&self, _parsed: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Err(ProblemError::Unimplemented)
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Err(ProblemError::Unimplemented)
+    }
+
+    /// Parses the input and solves a single part of it without displaying anything, for when
+    /// nothing else needs the parsed model
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, ProblemError> {
+        let parsed = self.parse(input)?;
+        let ctx = SolveContext::headless(part);
+
+        match part {
+            Part::One => self.part1(&parsed, &ctx),
+            Part::Two => self.part2(&parsed, &ctx),
+        }
     }
+}
+
+/// A [`Problem`] with its parsed type hidden, so that every problem can sit in the same registry.
+/// `Send` so the registry can be handed to the thread that solves while the display runs
+pub trait AnyProblem: Send {
+    fn day(&self) -> usize;
+
+    fn metadata(&self) -> Metadata;
+
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn PreparedProblem + 'a>, ProblemError>;
+
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, ProblemError>;
+}
+
+/// A problem together with the parsed input that both of its parts are solved from
+pub trait PreparedProblem {
+    fn solve(&self, ctx: &SolveContext) -> Result<Answer, ProblemError>;
+}
+
+struct Prepared<'a, P: Problem> {
+    problem: &'a P,
+    parsed: P::Parsed,
+}
+
+impl<P: Problem + Send> AnyProblem for P {
+    fn day(&self) -> usize {
+        P::DAY
+    }
+
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            title: P::TITLE,
+            tags: P::TAGS,
+            visualised: P::VISUALISED,
+            runtime: P::RUNTIME,
+        }
+    }
+
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn PreparedProblem + 'a>, ProblemError> {
+        Ok(Box::new(Prepared {
+            problem: self,
+            parsed: self.parse(input)?,
+        }))
+    }
+
+    fn solve(&self, part: Part, input: &str) -> Result<Answer, ProblemError> {
+        Problem::solve(self, part, input)
+    }
+}
 
-    fn part2(&self, _input: &str) -> T {
-        todo!()
+impl<P: Problem> PreparedProblem for Prepared<'_, P> {
+    fn solve(&self, ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        match ctx.part() {
+            Part::One => self.problem.part1(&self.parsed, ctx),
+            Part::Two => self.problem.part2(&self.parsed, ctx),
+        }
     }
 }
 
-pub fn get_all_problems(tx: &Sender<Event>) -> Vec<Box<dyn Problem<u128>>> {
-    vec![
-        Box::new(Problem1 {}),
-        Box::new(Problem2::new(tx)),
-        Box::new(Problem3::new(tx)),
-        Box::new(Problem4 {}),
-        Box::new(Problem5::new(tx)),
-        Box::new(Problem6::new(tx)),
-        Box::new(Problem7 {} ),
-        Box::new(Problem8 {} ),
-        Box::new(Problem9 {} ),
-        Box::new(Problem10 {} ),
-        Box::new(Problem11 {} ),
-        Box::new(Problem12 {} ),
-        Box::new(Problem13 {} ),
-        Box::new(Problem14 {tx: tx.clone()} ),
-    ]
+pub fn problem_registry(year: i32) -> crate::Result<Registry> {
+    Registry::new(year, registered_problems(year))
 }
@@ -1,5 +1,7 @@
+use crate::problems::ProblemError;
 use std::fmt;
 use std::fmt::Write;
+use std::str::FromStr;
 
 #[derive(Clone)]
 pub struct Grid<T>
@@ -55,7 +57,7 @@ where
             .flat_map(|(row, line)| {
                 line.iter()
                     .enumerate()
-                    .map(move |(col, &ref c)| ((row as i32, col as i32), c.clone()))
+                    .map(move |(col, c)| ((row as i32, col as i32), c.clone()))
             })
             .collect::<Vec<_>>()
             .into_iter()
@@ -69,12 +71,13 @@ where
     pub fn from_lines(lines: Vec<Vec<T>>) -> Self {
         Self {
             row_count: lines.len(),
-            col_count: lines[0].len(),
+            col_count: lines.first().map_or(0, Vec::len),
             lines,
         }
     }
 
-    pub fn from_string (input: &str) -> Self {
+    /// Fails if the input has no cells at all
+    pub fn from_string(input: &str) -> Result<Self, ProblemError> {
         let lines = input
             .lines()
             .map(|l| {
@@ -83,9 +86,13 @@ where
                     .flat_map(|c| <T as FromChar<T>>::from_char(c))
                     .collect::<Vec<T>>()
             })
-            .collect();
+            .collect::<Vec<_>>();
 
-        Self::from_lines(lines)
+        if lines.iter().all(Vec::is_empty) {
+            return Err(ProblemError::parse(1, 1, "expected a grid"));
+        }
+
+        Ok(Self::from_lines(lines))
     }
 
     pub fn is_in_bounds(&self, point: &(i32, i32)) -> bool {
@@ -95,3 +102,26 @@ where
             && point.1 < self.col_count as i32
     }
 }
+
+/// Parses `field`, which must be a slice of `line`, reporting where it sits in the input if it
+/// isn't valid. `line_number` starts at 1
+pub fn parse_field<T>(line_number: usize, line: &str, field: &str) -> Result<T, ProblemError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    field.parse().map_err(|e| {
+        let (_, column) = position_in(line, field);
+
+        ProblemError::parse(line_number, column, format!("{field:?}: {e}"))
+    })
+}
+
+/// Line and column, both starting at 1, at which `field` starts within `input`. `field` must be a
+/// slice of `input`
+pub fn position_in(input: &str, field: &str) -> (usize, usize) {
+    let before = &input[..field.as_ptr() as usize - input.as_ptr() as usize];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+    (before.matches('\n').count() + 1, before.len() - line_start + 1)
+}
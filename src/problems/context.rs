@@ -0,0 +1,124 @@
+use crate::display::{AppDisplayState, GridStyle};
+use crate::problems::common::Grid;
+use crate::Event::{NewRowEvent, UpdateAppDisplayState};
+use crate::{Answer, Event, Part};
+use ratatui::text::Line;
+use std::cell::Cell;
+use std::sync::mpsc::Sender;
+
+/// What a part can report while it's being solved. Without a display attached, every report is
+/// dropped
+pub struct SolveContext {
+    part: Part,
+    tx: Option<Sender<Event>>,
+    /// The last progress sent, as a whole percentage, so only changes the display can show are
+    /// sent
+    percent_shown: Cell<Option<u32>>,
+}
+
+impl SolveContext {
+    pub fn headless(part: Part) -> Self {
+        SolveContext {
+            part,
+            tx: None,
+            percent_shown: Cell::new(None),
+        }
+    }
+
+    pub fn displayed(part: Part, tx: &Sender<Event>) -> Self {
+        SolveContext {
+            part,
+            tx: Some(tx.clone()),
+            percent_shown: Cell::new(None),
+        }
+    }
+
+    pub fn part(&self) -> Part {
+        self.part
+    }
+
+    /// How far through the part the solver is, from 0.0 to 1.0. Cheap enough to call on every
+    /// step, as nothing is sent until the whole percentage changes
+    pub fn progress(&self, fraction: f64) {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let percent = (fraction * 100.0) as u32;
+        if self.percent_shown.replace(Some(percent)) == Some(percent) {
+            return;
+        }
+
+        self.send(|| {
+            UpdateAppDisplayState(AppDisplayState {
+                progress: Some(fraction),
+                ..AppDisplayState::default()
+            })
+        });
+    }
+
+    /// The answer so far, shown in place of the final answer until the part finishes
+    pub fn partial_answer(&self, answer: impl Into<Answer>) {
+        self.send(|| {
+            UpdateAppDisplayState(match self.part {
+                Part::One => AppDisplayState::part_1_only(answer),
+                Part::Two => AppDisplayState::part_2_only(answer),
+            })
+        });
+    }
+
+    pub fn log_line(&self, line: impl Into<Line<'static>>) {
+        self.send(|| NewRowEvent(line.into()));
+    }
+
+    /// Shows the grid, drawing its cells as `style` says. `GridStyle::default()` draws plain text
+    pub fn show_grid(&self, grid: &Grid<char>, style: &GridStyle) {
+        self.send(|| UpdateAppDisplayState(AppDisplayState::grid_update(grid.clone(), style.clone())));
+    }
+
+    fn send(&self, event: impl FnOnce() -> Event) {
+        if let Some(tx) = &self.tx {
+            // The display going away (e.g. after quitting) shouldn't stop the solver
+            let _ = tx.send(event());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::PartStatus;
+    use std::sync::mpsc;
+
+    #[test]
+    fn should_report_partial_answer_against_its_own_part() {
+        let (tx, rx) = mpsc::channel();
+
+        SolveContext::displayed(Part::Two, &tx).partial_answer(5);
+
+        match rx.try_recv() {
+            Ok(UpdateAppDisplayState(state)) => {
+                assert_eq!(state.part_1_result, PartStatus::Pending);
+                assert_eq!(state.part_2_result, PartStatus::Solved(Answer::from(5)));
+            }
+            _ => panic!("Expected a display update"),
+        }
+    }
+
+    #[test]
+    fn should_only_send_progress_when_whole_percentage_changes() {
+        let (tx, rx) = mpsc::channel();
+        let ctx = SolveContext::displayed(Part::One, &tx);
+
+        for i in 0..10000 {
+            ctx.progress(i as f64 / 10000.0);
+        }
+
+        assert_eq!(rx.try_iter().count(), 100);
+    }
+
+    #[test]
+    fn should_keep_solving_once_display_has_gone() {
+        let (tx, rx) = mpsc::channel();
+        drop(rx);
+
+        SolveContext::displayed(Part::One, &tx).log_line("still going");
+    }
+}
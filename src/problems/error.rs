@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProblemError {
+    /// The input couldn't be understood. Lines and columns both start at 1
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    Unimplemented,
+    Failed(String),
+}
+
+impl ProblemError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ProblemError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn failed(message: impl Into<String>) -> Self {
+        ProblemError::Failed(message.into())
+    }
+}
+
+impl fmt::Display for ProblemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProblemError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at line {line}, column {column}: {message}"),
+            ProblemError::Unimplemented => write!(f, "not implemented"),
+            ProblemError::Failed(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl Error for ProblemError {}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// What kind of puzzle a problem is
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tag {
+    Grid,
+    Graph,
+    Math,
+    Parsing,
+    Search,
+    Simulation,
+    Sorting,
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tag::Grid => write!(f, "grid"),
+            Tag::Graph => write!(f, "graph"),
+            Tag::Math => write!(f, "math"),
+            Tag::Parsing => write!(f, "parsing"),
+            Tag::Search => write!(f, "search"),
+            Tag::Simulation => write!(f, "simulation"),
+            Tag::Sorting => write!(f, "sorting"),
+        }
+    }
+}
+
+/// Roughly how long a problem takes to solve a real input in a release build
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Runtime {
+    /// Under a second
+    #[default]
+    Fast,
+    /// Seconds or more
+    Slow,
+}
+
+impl fmt::Display for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Runtime::Fast => write!(f, "fast"),
+            Runtime::Slow => write!(f, "slow"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub title: &'static str,
+    pub tags: &'static [Tag],
+    /// Whether solving it shows more than the answers, e.g. a grid or a log
+    pub visualised: bool,
+    pub runtime: Runtime,
+}
@@ -1,57 +1,73 @@
-use crate::problems::Problem;
+use crate::problems::common::parse_field;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use std::collections::HashMap;
 
 #[derive(Clone)]
 pub struct Problem1 {}
 
-impl Problem<u128> for Problem1 {
-    fn part1(&self, _input: &str) -> u128 {
-        let (mut left_list, mut right_list): (Vec<u128>, Vec<u128>) = _input
-            .lines()
-            .map(|line| {
-                let parts: Vec<u128> = line.split(" ").flat_map(|s| s.parse::<u128>()).collect();
+impl Problem for Problem1 {
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+    const TAGS: &'static [Tag] = &[Tag::Sorting];
+    type Parsed = Vec<(u128, u128)>;
 
-                (parts[0], parts[1])
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let parts = line
+                    .split_whitespace()
+                    .map(|s| parse_field::<u128>(i + 1, line, s))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                match parts[..] {
+                    [left, right] => Ok((left, right)),
+                    _ => Err(ProblemError::parse(i + 1, 1, "expected two location IDs")),
+                }
             })
-            .unzip();
+            .collect()
+    }
+
+    fn part1(&self, parsed: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        let (mut left_list, mut right_list): (Vec<u128>, Vec<u128>) =
+            parsed.iter().copied().unzip();
 
         left_list.sort();
         right_list.sort();
 
-        left_list
+        Ok(left_list
             .into_iter()
             .zip(right_list)
             .fold(0, |acc, (a, b)| acc + b.abs_diff(a))
+            .into())
     }
 
-    fn part2(&self, _input: &str) -> u128 {
+    fn part2(&self, parsed: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
         let mut right_counts: HashMap<u128, u128> = HashMap::new();
 
-        _input
-            .lines()
-            .map(|line| {
-                let parts: Vec<u128> = line.split(" ").flat_map(|s| s.parse::<u128>()).collect();
+        parsed.iter().for_each(|&(_, right)| {
+            right_counts
+                .entry(right)
+                .and_modify(|c| *c += 1)
+                .or_insert(1);
+        });
 
-                right_counts
-                    .entry(parts[1])
-                    .and_modify(|c| *c += 1)
-                    .or_insert(1);
-
-                parts[0]
-            })
-            .collect::<Vec<u128>>()
-            .into_iter()
-            .fold(0u128, |acc, num| match right_counts.get(&num) {
+        Ok(parsed
+            .iter()
+            .fold(0u128, |acc, (num, _)| match right_counts.get(num) {
                 None => acc,
                 Some(count) => acc + count * num,
             })
+            .into())
     }
-
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
     const P: Problem1 = Problem1 {};
 
     #[test]
@@ -61,7 +77,7 @@ mod tests {
         2 2\n\
         1 3";
 
-        assert_eq!(P.part1(sample_input), 0);
+        assert_eq!(P.solve(Part::One, sample_input), Ok(Answer::from(0)));
     }
 
     #[test]
@@ -70,7 +86,7 @@ mod tests {
         4 1\n\
         2 2\n\
         1 3";
-        assert_eq!(P.part1(sample_input), 1);
+        assert_eq!(P.solve(Part::One, sample_input), Ok(Answer::from(1)));
     }
 
     #[test]
@@ -82,13 +98,13 @@ mod tests {
         1   3\n\
         3   9\n\
         3   3";
-        assert_eq!(P.part2(sample_input), 31);
+        assert_eq!(P.solve(Part::Two, sample_input), Ok(Answer::from(31)));
     }
 
     #[test]
     fn should_calculate_similarity_score_for_very_similar_list() {
         let sample_input = "7  7";
-        assert_eq!(P.part2(sample_input), 7);
+        assert_eq!(P.solve(Part::Two, sample_input), Ok(Answer::from(7)));
     }
 
     #[test]
@@ -96,7 +112,7 @@ mod tests {
         let sample_input = "\
         7  7\n\
         2  6";
-        assert_eq!(P.part2(sample_input), 7);
+        assert_eq!(P.solve(Part::Two, sample_input), Ok(Answer::from(7)));
     }
 
     #[test]
@@ -104,7 +120,7 @@ mod tests {
         let sample_input = "\
         7  7\n\
         2  7";
-        assert_eq!(P.part2(sample_input), 14);
+        assert_eq!(P.solve(Part::Two, sample_input), Ok(Answer::from(14)));
     }
 
     #[test]
@@ -112,6 +128,6 @@ mod tests {
         let sample_input = "\
         7  7\n\
         7  7";
-        assert_eq!(P.part2(sample_input), 7 * 2 * 2);
+        assert_eq!(P.solve(Part::Two, sample_input), Ok(Answer::from(7 * 2 * 2)));
     }
 }
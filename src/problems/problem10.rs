@@ -1,24 +1,32 @@
 use crate::problems::common::Grid;
-use crate::problems::Problem;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use itertools::Itertools;
 use std::collections::{HashSet, VecDeque};
 
 pub struct Problem10 {}
 
-impl Problem<u128> for Problem10 {
-    fn part1(&self, input: &str) -> u128 {
-        let grid: Grid<u32> = Grid::from_string(input);
+impl Problem for Problem10 {
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Hoof It";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Graph, Tag::Search];
+    type Parsed = Grid<u32>;
 
-        grid.clone()
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        Grid::from_string(input)
+    }
+
+    fn part1(&self, grid: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(grid.clone()
             .into_iter()
             .filter(|(_, val)| *val == 0)
             .map(|th| {
-                let mut to_explore = VecDeque::from([th.clone()]);
+                let mut to_explore = VecDeque::from([th]);
                 let mut trail_ends: HashSet<((i32, i32), u32)> = HashSet::new();
 
                 while !to_explore.is_empty() {
                     let ((coords_row, coords_col), val) = to_explore.pop_front().unwrap();
-                    let next_steps = vec![
+                    let next_steps = [
                         (coords_row - 1, coords_col),
                         (coords_row, coords_col + 1),
                         (coords_row + 1, coords_col),
@@ -34,7 +42,7 @@ impl Problem<u128> for Problem10 {
                             false
                         }
                     })
-                    .map(|coords| (coords.clone(), val + 1))
+                    .map(|coords| (*coords, val + 1))
                     .collect_vec();
 
                     if val == 8 {
@@ -44,25 +52,24 @@ impl Problem<u128> for Problem10 {
                     }
                 }
 
-                trail_ends.len() as u128
+                trail_ends.len()
             })
-            .sum()
+            .sum::<usize>()
+            .into())
     }
 
-    fn part2(&self, input: &str) -> u128 {
-        let grid: Grid<u32> = Grid::from_string(input);
-
-        grid.clone()
+    fn part2(&self, grid: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(grid.clone()
             .into_iter()
             .filter(|(_, val)| *val == 0)
             .map(|th| {
-                let mut to_explore = VecDeque::from([vec![th.clone()]]);
+                let mut to_explore = VecDeque::from([vec![th]]);
                 let mut paths: HashSet<Vec<((i32, i32), u32)>> = HashSet::new();
 
                 while !to_explore.is_empty() {
                     let path_so_far = to_explore.pop_front().unwrap();
                     let ((coords_row, coords_col), val) = path_so_far.last().unwrap();
-                    let new_paths_so_far = vec![
+                    let new_paths_so_far = [
                         (*coords_row - 1, *coords_col),
                         (*coords_row, *coords_col + 1),
                         (*coords_row + 1, *coords_col),
@@ -81,7 +88,7 @@ impl Problem<u128> for Problem10 {
                     .map(|coords| {
                         let mut new_vec = path_so_far.clone();
 
-                        new_vec.push((coords.clone(), val + 1));
+                        new_vec.push((*coords, val + 1));
 
                         new_vec
                     })
@@ -94,21 +101,24 @@ impl Problem<u128> for Problem10 {
                     }
                 }
 
-                paths.len() as u128
+                paths.len()
             })
-            .sum()
+            .sum::<usize>()
+            .into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
 
     #[test]
     fn should_produce_correct_answer_for_part_1() {
         let p = Problem10 {};
         assert_eq!(
-            p.part1(
+            p.solve(
+                Part::One,
                 "\
                 89010123\n\
                 78121874\n\
@@ -120,7 +130,7 @@ mod tests {
                 10456732
                 "
             ),
-            36
+            Ok(Answer::from(36))
         );
     }
 
@@ -128,7 +138,8 @@ mod tests {
     fn should_produce_correct_answer_for_part_2() {
         let p = Problem10 {};
         assert_eq!(
-            p.part2(
+            p.solve(
+                Part::Two,
                 "\
                 89010123\n\
                 78121874\n\
@@ -139,7 +150,7 @@ mod tests {
                 01329801\n\
                 10456732"
             ),
-            81
+            Ok(Answer::from(81))
         )
     }
 }
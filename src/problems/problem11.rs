@@ -1,27 +1,40 @@
-use crate::problems::Problem;
+use crate::problems::common::parse_field;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use itertools::Itertools;
 use std::collections::HashMap;
 
 pub struct Problem11 {}
 
-impl Problem<u128> for Problem11 {
-    fn part1(&self, input: &str) -> u128 {
-        self.solve(input, 25)
+impl Problem for Problem11 {
+    const DAY: usize = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+    const TAGS: &'static [Tag] = &[Tag::Math, Tag::Simulation];
+    type Parsed = HashMap<u64, usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        let line = input.lines().next().unwrap_or_default();
+
+        Ok(line
+            .split(" ")
+            .map(|x| parse_field::<u64>(1, line, x))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .counts())
     }
 
-    fn part2(&self, input: &str) -> u128 {
-        self.solve(input, 75)
+    fn part1(&self, stones: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(self.count_stones(stones, 25).into())
+    }
+
+    fn part2(&self, stones: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(self.count_stones(stones, 75).into())
     }
 }
 
 impl Problem11 {
-    fn solve(&self, input: &str, iterations: usize) -> u128 {
-        let mut state = input
-            .strip_suffix("\n")
-            .unwrap()
-            .split(" ")
-            .map(|x| x.parse::<u64>().unwrap())
-            .counts();
+    fn count_stones(&self, stones: &HashMap<u64, usize>, iterations: usize) -> u128 {
+        let mut state = stones.clone();
 
         (0..iterations).for_each(|_| state = state.evolve());
 
@@ -37,7 +50,7 @@ impl Evolve for HashMap<u64, usize> {
     fn evolve(&self) -> Self {
         let mut map = HashMap::new();
 
-        self.into_iter().for_each(|(&index, count_appearances)| {
+        self.iter().for_each(|(&index, count_appearances)| {
             if index == 0 {
                 *map.entry(1).or_default() += count_appearances;
             } else if (index).ilog10() % 2 == 1 {
@@ -59,16 +72,17 @@ impl Evolve for HashMap<u64, usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
 
     #[test]
     fn should_produce_correct_answer_for_part_1() {
         let p = Problem11 {};
-        assert_eq!(p.part1("125 17\n"), 55312);
+        assert_eq!(p.solve(Part::One, "125 17\n"), Ok(Answer::from(55312)));
     }
 
     #[test]
     fn should_produce_correct_answer_for_part_2() {
         let p = Problem11 {};
-        assert_eq!(p.part2("125 17\n"), 65601038650482);
+        assert_eq!(p.solve(Part::Two, "125 17\n"), Ok(Answer::Integer(65601038650482)));
     }
 }
@@ -1,5 +1,6 @@
 use crate::problems::common::Grid;
-use crate::problems::Problem;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use itertools::Itertools;
 use std::collections::{BTreeSet, HashSet};
 
@@ -74,9 +75,9 @@ impl PlantArea for Group {
                     .collect_vec();
 
                 // then same for columns left/right
-                let edge_count_for_row = Problem12::find_edges(&row_columns, &above_columns)
-                    + Problem12::find_edges(&row_columns, &below_columns);
-                edge_count_for_row
+
+                Problem12::find_edges(&row_columns, &above_columns)
+                    + Problem12::find_edges(&row_columns, &below_columns)
             })
             .sum::<u128>();
 
@@ -114,9 +115,9 @@ impl PlantArea for Group {
                     .sorted()
                     .collect_vec();
 
-                let edge_count_for_column = Problem12::find_edges(&col_rows, &left_rows)
-                    + Problem12::find_edges(&col_rows, &right_rows);
-                edge_count_for_column
+
+                Problem12::find_edges(&col_rows, &left_rows)
+                    + Problem12::find_edges(&col_rows, &right_rows)
             })
             .sum::<u128>();
 
@@ -154,9 +155,7 @@ impl Problem12 {
 
             let mut nodes_to_visit = vec![unvisited_places.pop_first().unwrap()];
 
-            while !nodes_to_visit.is_empty() {
-                let node = nodes_to_visit.pop().unwrap();
-
+            while let Some(node) = nodes_to_visit.pop() {
                 new_group.push(node);
                 unvisited_places.remove(&node);
 
@@ -171,7 +170,7 @@ impl Problem12 {
                 .collect();
 
                 nodes_to_visit.iter().for_each(|node| {
-                    unvisited_places.remove(&node);
+                    unvisited_places.remove(node);
                 });
                 nodes_to_visit.extend(nodes_to_add);
             }
@@ -205,38 +204,47 @@ impl Problem12 {
         }
     }
 
-    fn solve(&self, input: &str, g_f: fn(&Group) -> u128) -> u128 {
-        let grid = Grid::from_string(input);
+    fn total_cost(&self, grid: &Grid<char>, g_f: fn(&Group) -> u128) -> u128 {
+        let groups = Self::find_groups(grid);
 
-        let groups = Self::find_groups(&grid);
-
-        groups.iter().map(|g| g_f(g)).sum()
+        groups.iter().map(g_f).sum()
     }
 }
 
-impl Problem<u128> for Problem12 {
-    fn part1(&self, input: &str) -> u128 {
+impl Problem for Problem12 {
+    const DAY: usize = 12;
+    const TITLE: &'static str = "Garden Groups";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Graph];
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        Grid::from_string(input)
+    }
+
+    fn part1(&self, grid: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
         let g_f = |g: &Group| g.get_cost();
 
-        self.solve(input, g_f)
+        Ok(self.total_cost(grid, g_f).into())
     }
 
-    fn part2(&self, input: &str) -> u128 {
+    fn part2(&self, grid: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
         let g_f = |g: &Group| g.get_bulk_cost();
 
-        self.solve(input, g_f)
+        Ok(self.total_cost(grid, g_f).into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
 
     #[test]
     fn should_produce_correct_answer_for_part_1() {
         let p = Problem12 {};
         assert_eq!(
-            p.part1(
+            p.solve(
+                Part::One,
                 "\
             RRRRIICCFF\n\
             RRRRIICCCF\n\
@@ -249,7 +257,7 @@ mod tests {
             MIIISIJEEE\n\
             MMMISSJEEE"
             ),
-            1930
+            Ok(Answer::from(1930))
         );
     }
 
@@ -257,7 +265,8 @@ mod tests {
     fn should_produce_correct_answer_for_part_2() {
         let p = Problem12 {};
         assert_eq!(
-            p.part2(
+            p.solve(
+                Part::Two,
                 "\
             RRRRIICCFF\n\
             RRRRIICCCF\n\
@@ -270,7 +279,7 @@ mod tests {
             MIIISIJEEE\n\
             MMMISSJEEE"
             ),
-            1206
+            Ok(Answer::from(1206))
         );
     }
 
@@ -288,7 +297,8 @@ mod tests {
             AABB\n\
             ACCB\n
             ",
-            );
+            )
+            .unwrap();
 
             assert_eq!(
                 Problem12::find_groups(&g),
@@ -315,7 +325,8 @@ mod tests {
             AABB\n\
             ACCB\n
             ",
-            );
+            )
+            .unwrap();
 
             let costs = Problem12::find_groups(&g)
                 .iter()
@@ -328,13 +339,13 @@ mod tests {
             use super::*;
 
             fn get_a_group_from_grid(grid_string: &str) -> Group {
-                let g: Grid<char> = Grid::from_string(grid_string);
+                let g: Grid<char> = Grid::from_string(grid_string).unwrap();
 
                 let groups = Problem12::find_groups(&g);
 
                 groups
                     .into_iter()
-                    .find(|g| g.iter().any(|x| (*x).1 == 'A'))
+                    .find(|g| g.iter().any(|x| x.1 == 'A'))
                     .unwrap()
             }
 
@@ -1,37 +1,82 @@
-use crate::problems::Problem;
-use itertools::Itertools;
+use crate::problems::common::position_in;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use nalgebra::{Matrix2, Vector2};
 use regex::Regex;
 
 pub struct Problem13 {}
 
 #[derive(Debug, PartialEq, Eq)]
-struct Machine {
+pub struct Machine {
     a_button: (u64, u64),
     b_button: (u64, u64),
     prize_location: (u64, u64),
 }
 
-impl Problem<u128> for Problem13 {
-    fn part1(&self, input: &str) -> u128 {
-        Problem13::solve(input, 0)
+impl Problem for Problem13 {
+    const DAY: usize = 13;
+    const TITLE: &'static str = "Claw Contraption";
+    const TAGS: &'static [Tag] = &[Tag::Math];
+    type Parsed = Vec<Machine>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        let machine_regex: Regex = Regex::new(
+            r"Button A: X\+(\d+), Y\+(\d+)\nButton B: X\+(\d+), Y\+(\d+)\nPrize: X=(\d+), Y=(\d+)",
+        )
+        .unwrap();
+
+        // Every number is a slice of the input, so its position can be found from it
+        let number = |field: &str| {
+            field.parse::<u64>().map_err(|e| {
+                let (line, column) = position_in(input, field);
+
+                ProblemError::parse(line, column, format!("{field:?}: {e}"))
+            })
+        };
+
+        let mut line_number = 1;
+
+        input
+            .split("\n\n")
+            .map(|item| {
+                let first_line_number = line_number;
+                line_number += item.lines().count() + 1;
+
+                let (_, [a_x, a_y, b_x, b_y, p_x, p_y]) = machine_regex
+                    .captures(item.trim())
+                    .ok_or_else(|| {
+                        ProblemError::parse(first_line_number, 1, "expected a claw machine")
+                    })?
+                    .extract();
+
+                Ok(Machine {
+                    a_button: (number(a_x)?, number(a_y)?),
+                    b_button: (number(b_x)?, number(b_y)?),
+                    prize_location: (number(p_x)?, number(p_y)?),
+                })
+            })
+            .collect()
     }
 
-    fn part2(&self, input: &str) -> u128 {
-        Problem13::solve(input, 10000000000000)
+    fn part1(&self, machines: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(Problem13::solve(machines, 0)?.into())
+    }
+
+    fn part2(&self, machines: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(Problem13::solve(machines, 10000000000000)?.into())
     }
 }
 
 impl Problem13 {
     const FLOAT_THRESHOLD: f64 = 0.001;
 
-    fn solve(input: &str, offset: u64) -> u128 {
-        Problem13::parse(input, offset)
+    fn solve(machines: &[Machine], offset: u64) -> Result<u128, ProblemError> {
+        machines
             .iter()
             .map(|machine| {
                 let final_vector_in_x_y = Vector2::new(
-                    machine.prize_location.0 as f64,
-                    machine.prize_location.1 as f64,
+                    (machine.prize_location.0 + offset) as f64,
+                    (machine.prize_location.1 + offset) as f64,
                 );
                 let change_of_basis_matrix = Matrix2::new(
                     machine.a_button.0 as f64,
@@ -39,7 +84,9 @@ impl Problem13 {
                     machine.a_button.1 as f64,
                     machine.b_button.1 as f64,
                 );
-                let inverse = change_of_basis_matrix.try_inverse().unwrap();
+                let inverse = change_of_basis_matrix.try_inverse().ok_or_else(|| {
+                    ProblemError::failed(format!("buttons of {machine:?} move in the same direction"))
+                })?;
 
                 let vector_in_new_basis = inverse * final_vector_in_x_y;
                 if (vector_in_new_basis.x - vector_in_new_basis.x.round()).abs()
@@ -47,48 +94,27 @@ impl Problem13 {
                     && (vector_in_new_basis.y - vector_in_new_basis.y.round()).abs()
                         < Self::FLOAT_THRESHOLD
                 {
-                    vector_in_new_basis.x.round() as u128 * 3
-                        + vector_in_new_basis.y.round() as u128
+                    Ok(vector_in_new_basis.x.round() as u128 * 3
+                        + vector_in_new_basis.y.round() as u128)
                 } else {
-                    0
+                    Ok(0)
                 }
             })
             .sum()
     }
-
-    fn parse(input: &str, offset: u64) -> Vec<Machine> {
-        let machine_regex: Regex = Regex::new(
-            r"Button A: X\+(\d+), Y\+(\d+)\nButton B: X\+(\d+), Y\+(\d+)\nPrize: X=(\d+), Y=(\d+)",
-        )
-        .unwrap();
-
-        input
-            .split("\n\n")
-            .map(
-                |item| match machine_regex.captures(item.trim()).unwrap().extract() {
-                    (_, [a_x, a_y, b_x, b_y, p_x, p_y]) => Machine {
-                        a_button: (a_x.parse::<u64>().unwrap(), a_y.parse::<u64>().unwrap()),
-                        b_button: (b_x.parse::<u64>().unwrap(), b_y.parse::<u64>().unwrap()),
-                        prize_location: (
-                            p_x.parse::<u64>().unwrap() + offset,
-                            p_y.parse::<u64>().unwrap() + offset,
-                        ),
-                    },
-                },
-            )
-            .collect_vec()
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
 
     #[test]
     fn should_produce_correct_answer_for_part_1() {
         let p = Problem13 {};
         assert_eq!(
-            p.part1(
+            p.solve(
+                Part::One,
                 "\
             Button A: X+94, Y+34\n\
             Button B: X+22, Y+67\n\
@@ -107,7 +133,7 @@ mod tests {
             Prize: X=18641, Y=10279\n\
             "
             ),
-            480
+            Ok(Answer::from(480))
         );
     }
 
@@ -115,7 +141,8 @@ mod tests {
     fn should_produce_correct_answer_for_part_2() {
         let p = Problem13 {};
         assert_eq!(
-            p.part2(
+            p.solve(
+                Part::Two,
                 "\
             Button A: X+94, Y+34\n\
             Button B: X+22, Y+67\n\
@@ -134,7 +161,7 @@ mod tests {
             Prize: X=18641, Y=10279\n\
             "
             ),
-            875318608908
+            Ok(Answer::Integer(875318608908))
         );
     }
 
@@ -147,7 +174,7 @@ mod tests {
             #[test]
             fn should_parse_input_correctly() {
                 assert_eq!(
-                    Problem13::parse(
+                    Problem13 {}.parse(
                         "\
                         Button A: X+1, Y+2\n\
                         Button B: X+3, Y+4\n\
@@ -155,21 +182,53 @@ mod tests {
                         \n\
                         Button A: X+7, Y+8\n\
                         Button B: X+9, Y+10\n\
-                        Prize: X=11, Y=12\n",
-                        10
+                        Prize: X=11, Y=12\n"
                     ),
-                    vec![
+                    Ok(vec![
                         Machine {
                             a_button: (1, 2),
                             b_button: (3, 4),
-                            prize_location: (15, 16)
+                            prize_location: (5, 6)
                         },
                         Machine {
                             a_button: (7, 8),
                             b_button: (9, 10),
-                            prize_location: (21, 22)
+                            prize_location: (11, 12)
                         }
-                    ]
+                    ])
+                )
+            }
+
+            #[test]
+            fn should_report_line_of_malformed_machine() {
+                assert_eq!(
+                    Problem13 {}.parse(
+                        "\
+                        Button A: X+1, Y+2\n\
+                        Button B: X+3, Y+4\n\
+                        Prize: X=5, Y=6\n\
+                        \n\
+                        Button A: X+7, Y+8\n\
+                        Prize: X=11, Y=12\n"
+                    ),
+                    Err(ProblemError::parse(5, 1, "expected a claw machine"))
+                )
+            }
+
+            #[test]
+            fn should_report_position_of_number_too_big_to_read() {
+                assert_eq!(
+                    Problem13 {}.parse(
+                        "\
+                        Button A: X+1, Y+2\n\
+                        Button B: X+3, Y+4\n\
+                        Prize: X=5, Y=99999999999999999999\n"
+                    ),
+                    Err(ProblemError::parse(
+                        3,
+                        15,
+                        "\"99999999999999999999\": number too large to fit in target type"
+                    ))
                 )
             }
         }
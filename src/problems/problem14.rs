@@ -1,19 +1,16 @@
-use crate::display::AppDisplayState;
-use crate::problems::common::Grid;
-use crate::problems::Problem;
-use crate::Event;
-use crate::Event::UpdateAppDisplayState;
+use crate::problems::common::{parse_field, Grid};
+use crate::display::GridStyle;
+use crate::problems::{Problem, ProblemError, Runtime, SolveContext, Tag};
+use crate::Answer;
 use itertools::Itertools;
+use ratatui::style::{Color, Style};
 use regex::Regex;
-use std::sync::mpsc;
-use std::sync::mpsc::Sender;
+use std::collections::HashMap;
 
-pub struct Problem14 {
-    pub(crate) tx: mpsc::Sender<Event>,
-}
+pub struct Problem14 {}
 
 #[derive(Debug, Clone, Copy)]
-struct Robot {
+pub struct Robot {
     position: (isize, isize),
     velocity: (isize, isize),
 }
@@ -27,13 +24,23 @@ impl Robot {
     }
 }
 
-impl Problem<u128> for Problem14 {
-    fn part1(&self, input: &str) -> u128 {
-        Problem14::solve(input, 101, 103, 100)
+impl Problem for Problem14 {
+    const DAY: usize = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    const VISUALISED: bool = true;
+    const RUNTIME: Runtime = Runtime::Slow;
+    type Parsed = Vec<Robot>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        Problem14::parse_robots(input)
+    }
+
+    fn part1(&self, robots: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(Problem14::solve(robots, 101, 103, 100).into())
     }
 
-    fn part2(&self, input: &str) -> u128 {
-        let robots = Problem14::parse(input);
+    fn part2(&self, robots: &Self::Parsed, ctx: &SolveContext) -> Result<Answer, ProblemError> {
         let grid_width = 101;
         let grid_height = 103;
 
@@ -53,24 +60,25 @@ impl Problem<u128> for Problem14 {
         let answer = (0..10000)
             .rev()
             .max_by_key(|i| {
+                ctx.progress(1.0 - *i as f64 / 10000.0);
                 Problem14::solve_given_robots_and_function(&mut robots.clone(), grid_width, grid_height, *i, most_robots_in_row)
             })
             .unwrap() as u128;
 
-        let mut last_robots = robots.clone();
+        let mut last_robots = robots.to_vec();
         Problem14::solve_given_robots(&mut last_robots, grid_width, grid_height, answer as isize);
-        Self::display_robots(&last_robots, grid_width, grid_height, &self.tx);
+        Self::display_robots(&last_robots, grid_width, grid_height, ctx);
 
-        answer
+        Ok(answer.into())
     }
 }
 
 impl Problem14 {
-    fn solve(input: &str, grid_width: isize, grid_height: isize, movements: isize) -> u128 {
-        Self::solve_given_robots(&mut Self::parse(input), grid_width, grid_height, movements)
+    fn solve(robots: &[Robot], grid_width: isize, grid_height: isize, movements: isize) -> u128 {
+        Self::solve_given_robots(&mut robots.to_vec(), grid_width, grid_height, movements)
     }
 
-    fn solve_given_robots(robots: &mut Vec<Robot>, grid_width: isize, grid_height: isize, movements: isize) -> u128 {
+    fn solve_given_robots(robots: &mut [Robot], grid_width: isize, grid_height: isize, movements: isize) -> u128 {
         let safety_factor_function = |robots: &Vec<Robot>, grid_width, grid_height| -> u128 {
             let upper_left_robots = robots
                 .iter()
@@ -102,46 +110,59 @@ impl Problem14 {
         Self::solve_given_robots_and_function(robots, grid_width, grid_height, movements, safety_factor_function)
     }
 
-    fn solve_given_robots_and_function(robots: &mut Vec<Robot>, grid_width: isize, grid_height: isize, movements: isize, function: fn(&Vec<Robot>, isize, isize) -> u128) -> u128 {
+    fn solve_given_robots_and_function(robots: &mut [Robot], grid_width: isize, grid_height: isize, movements: isize, function: fn(&Vec<Robot>, isize, isize) -> u128) -> u128 {
         let robots_at_end = robots
-            .into_iter()
+            .iter_mut()
             .map(|robot| {
                 robot.mv(movements, grid_width, grid_height);
 
-                robot.clone()
+                *robot
             })
             .collect_vec();
 
         function(&robots_at_end, grid_width, grid_height)
     }
 
-    fn display_robots(robots: &[Robot], grid_width: isize, grid_height: isize, tx: &Sender<Event>) {
+    fn display_robots(robots: &[Robot], grid_width: isize, grid_height: isize, ctx: &SolveContext) {
         let vofv = (0..grid_height).map(|row| {
             (0..grid_width).map(|col| {
                 let count = robots.iter().filter(|&robot| robot.position.1 == row && robot.position.0 == col).count();
-                let result = if count > 0 { '#' } else  { '.' };
-                result
+
+                if count > 0 { '#' } else  { '.' }
             })
                 .collect_vec()
         }).collect_vec();
 
         let g = Grid::from_lines(vofv);
+        let style = GridStyle {
+            cells: HashMap::from([
+                ('#', Style::new().fg(Color::Green)),
+                ('.', Style::new().fg(Color::DarkGray)),
+            ]),
+            ..GridStyle::default()
+        };
 
-        tx.send(UpdateAppDisplayState(AppDisplayState::grid_update(g))).unwrap();
+        ctx.show_grid(&g, &style);
     }
 
-    fn parse(input: &str) -> Vec<Robot> {
+    fn parse_robots(input: &str) -> Result<Vec<Robot>, ProblemError> {
         let line_regex = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
 
         input
             .lines()
-            .map(|line| match line_regex.captures(line).unwrap().extract() {
-                (_, [p_x, p_y, v_x, v_y]) => Robot {
-                    position: (p_x.parse().unwrap(), p_y.parse().unwrap()),
-                    velocity: (v_x.parse().unwrap(), v_y.parse().unwrap()),
-                },
+            .enumerate()
+            .map(|(i, line)| {
+                let (_, [p_x, p_y, v_x, v_y]) = line_regex
+                    .captures(line)
+                    .ok_or_else(|| ProblemError::parse(i + 1, 1, "expected a robot"))?
+                    .extract();
+
+                Ok(Robot {
+                    position: (parse_field(i + 1, line, p_x)?, parse_field(i + 1, line, p_y)?),
+                    velocity: (parse_field(i + 1, line, v_x)?, parse_field(i + 1, line, v_y)?),
+                })
             })
-            .collect_vec()
+            .collect()
     }
 }
 
@@ -153,19 +174,22 @@ mod tests {
     fn should_produce_correct_answer_for_part_1() {
         assert_eq!(
             Problem14::solve(
-                "\
-                p=0,4 v=3,-3\n\
-                p=6,3 v=-1,-3\n\
-                p=10,3 v=-1,2\n\
-                p=2,0 v=2,-1\n\
-                p=0,0 v=1,3\n\
-                p=3,0 v=-2,-2\n\
-                p=7,6 v=-1,-3\n\
-                p=3,0 v=-1,-2\n\
-                p=9,3 v=2,3\n\
-                p=7,3 v=-1,2\n\
-                p=2,4 v=2,-3\n\
-                p=9,5 v=-3,-3\n",
+                &Problem14::parse_robots(
+                    "\
+                    p=0,4 v=3,-3\n\
+                    p=6,3 v=-1,-3\n\
+                    p=10,3 v=-1,2\n\
+                    p=2,0 v=2,-1\n\
+                    p=0,0 v=1,3\n\
+                    p=3,0 v=-2,-2\n\
+                    p=7,6 v=-1,-3\n\
+                    p=3,0 v=-1,-2\n\
+                    p=9,3 v=2,3\n\
+                    p=7,3 v=-1,2\n\
+                    p=2,4 v=2,-3\n\
+                    p=9,5 v=-3,-3\n"
+                )
+                .unwrap(),
                 11,
                 7,
                 100
@@ -1,29 +1,33 @@
-use crate::problems::Problem;
+use crate::problems::{Problem, ProblemError, Tag};
 
+#[allow(dead_code, reason = "not registered until it's solved")]
 pub struct Problem15 {}
 
-impl Problem<u128> for Problem15 {}
+impl Problem for Problem15 {
+    const DAY: usize = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    type Parsed = ();
+
+    fn parse(&self, _input: &str) -> Result<Self::Parsed, ProblemError> {
+        Ok(())
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
 
     #[test]
-    #[should_panic]
-    fn should_produce_correct_answer_for_part_1() {
+    fn should_report_part_1_as_unimplemented() {
         let p = Problem15 {};
-        assert_eq!(
-            p.part1(
-                ""
-            ),
-            0
-        );
+        assert_eq!(p.solve(Part::One, ""), Err(ProblemError::Unimplemented));
     }
 
     #[test]
-    #[should_panic]
-    fn should_produce_correct_answer_for_part_2() {
+    fn should_report_part_2_as_unimplemented() {
         let p = Problem15 {};
-        assert_eq!(p.part2(""), 0);
+        assert_eq!(p.solve(Part::Two, ""), Err(ProblemError::Unimplemented));
     }
 }
@@ -1,12 +1,8 @@
-use crate::display::AppDisplayState;
-use crate::problems::Problem;
-use crate::Event;
-use crate::Event::UpdateAppDisplayState;
-use std::sync::mpsc::Sender;
-
-pub struct Problem2 {
-    tx: Sender<Event>,
-}
+use crate::problems::common::parse_field;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
+
+pub struct Problem2 {}
 
 #[derive(Debug, PartialEq, Eq)]
 enum ReportType {
@@ -16,10 +12,23 @@ enum ReportType {
     Unsafe,
 }
 
-impl Problem<u128> for Problem2 {
-    fn part1(&self, _input: &str) -> u128 {
+impl Problem for Problem2 {
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+    const TAGS: &'static [Tag] = &[Tag::Math];
+    type Parsed = Vec<Vec<i8>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| l.split(" ").map(|n| parse_field(i + 1, l, n)).collect())
+            .collect()
+    }
+
+    fn part1(&self, parsed: &Self::Parsed, ctx: &SolveContext) -> Result<Answer, ProblemError> {
         let mut partial_count = 0;
-        self.parse(_input)
+        Ok(parsed
             .iter()
             .filter(|l| {
                 let is_safe = self.is_safe(l);
@@ -28,20 +37,20 @@ impl Problem<u128> for Problem2 {
                     partial_count += 1;
                 }
 
-                self.tx
-                    .send(UpdateAppDisplayState(AppDisplayState::part_1_only(partial_count)))
-                    .unwrap_or_else(|e| println!("Error updating UI {:?}", e));
+                ctx.partial_answer(partial_count);
 
                 is_safe
             })
-            .count() as u128
+            .count()
+            .into())
     }
 
-    fn part2(&self, _input: &str) -> u128 {
-        self.parse(_input)
+    fn part2(&self, parsed: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(parsed
             .iter()
             .filter(|l| self.is_safe_with_bad_level(l))
-            .count() as u128
+            .count()
+            .into())
     }
 }
 
@@ -70,7 +79,7 @@ impl Problem2 {
                         ReportType::Unknown => {
                             if difference > 0 && difference <= 3 {
                                 (ReportType::Increasing, Some(w))
-                            } else if difference < 0 && difference >= -3 {
+                            } else if (-3..0).contains(&difference) {
                                 (ReportType::Decreasing, Some(w))
                             } else {
                                 (ReportType::Unsafe, Some(w))
@@ -84,7 +93,7 @@ impl Problem2 {
                             }
                         }
                         ReportType::Decreasing => {
-                            if difference < 0 && difference >= -3 {
+                            if (-3..0).contains(&difference) {
                                 (ReportType::Decreasing, Some(w))
                             } else {
                                 (ReportType::Unsafe, Some(w))
@@ -98,29 +107,19 @@ impl Problem2 {
             .0
             != ReportType::Unsafe
     }
-
-    fn parse(&self, input: &str) -> Vec<Vec<i8>> {
-        input
-            .lines()
-            .map(|l| l.split(" ").map(|n| n.parse::<i8>().unwrap()).collect())
-            .collect()
-    }
-
-    pub(crate) fn new(tx: &Sender<Event>) -> Problem2 {
-        Problem2 { tx: tx.clone() }
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::mpsc;
+    use crate::Part;
 
     #[test]
     fn should_return_correct_response_for_part1_example() {
-        let p: Problem2 = Problem2::new(&mpsc::channel().0);
+        let p = Problem2 {};
 
-        let result = p.part1(
+        let result = p.solve(
+            Part::One,
             "\
     7 6 4 2 1\n\
     1 2 7 8 9\n\
@@ -129,19 +128,19 @@ mod tests {
     8 6 4 4 1\n\
     1 3 6 7 9",
         );
-        assert_eq!(result, 2);
+        assert_eq!(result, Ok(Answer::from(2)));
     }
 
     #[test]
     fn is_safe_should_allow_increasing_small_interval_report() {
-        let p: Problem2 = Problem2::new(&mpsc::channel().0);
+        let p = Problem2 {};
 
         assert!(p.is_safe(&[1, 2, 5, 7, 8]))
     }
 
     #[test]
     fn is_safe_should_allow_increasing_small_interval_report_with_1_error_when_tolerated() {
-        let p: Problem2 = Problem2::new(&mpsc::channel().0);
+        let p = Problem2 {};
 
         assert!(p.is_safe_with_bad_level(&[9, 2, 5, 7, 8]));
         assert!(p.is_safe_with_bad_level(&[1, 9, 4, 7, 8]));
@@ -153,14 +152,14 @@ mod tests {
 
     #[test]
     fn is_safe_should_allow_decreasing_small_interval_report() {
-        let p: Problem2 = Problem2::new(&mpsc::channel().0);
+        let p = Problem2 {};
 
         assert!(p.is_safe(&[8, 7, 6, 3, 1]))
     }
 
     #[test]
     fn is_safe_should_allow_decreasing_small_interval_report_with_1_error_when_tolerated() {
-        let p: Problem2 = Problem2::new(&mpsc::channel().0);
+        let p = Problem2 {};
 
         assert!(p.is_safe_with_bad_level(&[15, 7, 6, 3, 1]));
         assert!(p.is_safe_with_bad_level(&[9, 15, 6, 3, 1]));
@@ -172,30 +171,31 @@ mod tests {
 
     #[test]
     fn is_safe_should_disallow_decreasing_too_big_interval() {
-        let p: Problem2 = Problem2::new(&mpsc::channel().0);
+        let p = Problem2 {};
 
         assert!(!p.is_safe(&[8, 7, 6, 2, 1]))
     }
 
     #[test]
     fn is_safe_should_disallow_increasing_too_big_interval() {
-        let p: Problem2 = Problem2::new(&mpsc::channel().0);
+        let p = Problem2 {};
 
         assert!(!p.is_safe(&[1, 2, 6, 7, 8]))
     }
 
     #[test]
     fn is_safe_should_disallow_non_monotonic_intervals() {
-        let p: Problem2 = Problem2::new(&mpsc::channel().0);
+        let p = Problem2 {};
 
         assert!(!p.is_safe(&[1, 2, 0, 4, 5]))
     }
 
     #[test]
     fn should_return_correct_response_for_part2_example() {
-        let p: Problem2 = Problem2::new(&mpsc::channel().0);
+        let p = Problem2 {};
 
-        let result = p.part2(
+        let result = p.solve(
+            Part::Two,
             "\
     7 6 4 2 1\n\
     1 2 7 8 9\n\
@@ -204,6 +204,6 @@ mod tests {
     8 6 4 4 1\n\
     1 3 6 7 9",
         );
-        assert_eq!(result, 4);
+        assert_eq!(result, Ok(Answer::from(4)));
     }
 }
@@ -1,79 +1,87 @@
-use crate::display::AppDisplayState;
+use crate::problems::common::position_in;
+use crate::problems::problem3::Instruction::{Do, Dont, Mul};
 use crate::problems::problem3::ProgramState::{Disabled, Enabled};
-use crate::problems::Problem;
-use crate::Event;
-use crate::Event::UpdateAppDisplayState;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use regex::Regex;
-use std::sync::mpsc::Sender;
 
-pub struct Problem3 {
-    tx: Sender<Event>,
-}
+pub struct Problem3 {}
 
-impl Problem<u128> for Problem3 {
-    fn part1(&self, _input: &str) -> u128 {
-        let instruction_re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
-        let mut partial_sum = 0u128;
+impl Problem for Problem3 {
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Mull It Over";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    type Parsed = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        let instruction_re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
 
         instruction_re
-            .captures_iter(_input)
-            .map(|c| match c.extract() {
-                (_, [l, r]) => match (l.parse::<u32>(), r.parse::<u32>()) {
-                    (Ok(l), Ok(r)) => {
-                        partial_sum += (l * r) as u128;
-                        self.tx
-                            .send(UpdateAppDisplayState(AppDisplayState::part_1_only(
-                                partial_sum,
-                            )))
-                            .unwrap_or_else(|e| println!("Error updating UI {:?}", e));
-                        (l * r) as u128
-                    }
-                    _ => panic!("Unable to parse numbers {l:?}, {r:?}"),
-                },
+            .captures_iter(input)
+            .map(|c| match c.get(0).unwrap().as_str() {
+                "do()" => Ok(Do),
+                "don't()" => Ok(Dont),
+                _ => {
+                    let parse_number = |i| {
+                        let number = c.get(i).unwrap().as_str();
+
+                        number.parse::<u32>().map_err(|e| {
+                            let (line, column) = position_in(input, number);
+
+                            ProblemError::parse(line, column, format!("{number:?}: {e}"))
+                        })
+                    };
+
+                    Ok(Mul(parse_number(1)?, parse_number(2)?))
+                }
             })
-            .sum()
+            .collect()
     }
 
-    fn part2(&self, _input: &str) -> u128 {
-        let instruction_re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
+    fn part1(&self, parsed: &Self::Parsed, ctx: &SolveContext) -> Result<Answer, ProblemError> {
         let mut partial_sum = 0u128;
 
-        instruction_re
-            .captures_iter(_input)
-            .fold((Enabled, 0u128), |(program_state, total), c| {
-                match (c.get(0).unwrap().as_str(), &program_state) {
-                    ("do()", _) => (Enabled, total),
-                    ("don't()", _) => (Disabled, total),
-                    (_, Enabled) => {
-                        match (
-                            c.get(1).unwrap().as_str().parse::<u32>(),
-                            c.get(2).unwrap().as_str().parse::<u32>(),
-                        ) {
-                            (Ok(l), Ok(r)) => {
-                                partial_sum += (l * r) as u128;
-                                self.tx
-                                    .send(UpdateAppDisplayState(AppDisplayState::part_2_only(
-                                        partial_sum,
-                                    )))
-                                    .unwrap_or_else(|e| println!("Error updating UI {:?}", e));
-
-
-                                (program_state, total + (l * r) as u128)
-                            }
-                            _ => panic!("Unable to parse numbers {c:?}"),
-                        }
+        Ok(parsed
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Mul(l, r) => {
+                    partial_sum += (l * r) as u128;
+                    ctx.partial_answer(partial_sum);
+                    Some((l * r) as u128)
+                }
+                Do | Dont => None,
+            })
+            .sum::<u128>()
+            .into())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed, ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        let mut partial_sum = 0u128;
+
+        Ok(parsed
+            .iter()
+            .fold((Enabled, 0u128), |(program_state, total), instruction| {
+                match (instruction, &program_state) {
+                    (Do, _) => (Enabled, total),
+                    (Dont, _) => (Disabled, total),
+                    (Mul(l, r), Enabled) => {
+                        partial_sum += (l * r) as u128;
+                        ctx.partial_answer(partial_sum);
+
+                        (program_state, total + (l * r) as u128)
                     }
-                    (_, Disabled) => (program_state, total),
+                    (Mul(..), Disabled) => (program_state, total),
                 }
             })
             .1
+            .into())
     }
 }
 
-impl Problem3 {
-    pub(crate) fn new(tx: &Sender<Event>) -> Problem3 {
-        Problem3 { tx: tx.clone() }
-    }
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
 }
 
 enum ProgramState {
@@ -84,25 +92,25 @@ enum ProgramState {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::mpsc;
+    use crate::Part;
 
     #[test]
     fn should_solve_part_1_example() {
-        let p: Problem3 = Problem3::new(&mpsc::channel().0);
+        let p = Problem3 {};
 
         assert_eq!(
-            p.part1("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"),
-            161
+            p.solve(Part::One, "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"),
+            Ok(Answer::from(161))
         );
     }
 
     #[test]
     fn should_solve_part_2_example() {
-        let p: Problem3 = Problem3::new(&mpsc::channel().0);
+        let p = Problem3 {};
 
         assert_eq!(
-            p.part2("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"),
-            48
+            p.solve(Part::Two, "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"),
+            Ok(Answer::from(48))
         );
     }
 }
@@ -1,5 +1,6 @@
 use crate::problems::problem4::DiagonalTypes::{DownLeft, DownRight, UpLeft, UpRight};
-use crate::problems::Problem;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use std::collections::HashSet;
 use crate::problems::common::Grid;
 
@@ -13,43 +14,51 @@ enum DiagonalTypes {
     UpLeft,
 }
 
-impl Problem<u128> for Problem4 {
-    fn part1(&self, _input: &str) -> u128 {
-        let grid = Grid::from_string(_input);
+impl Problem for Problem4 {
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Ceres Search";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Search];
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        Grid::from_string(input)
+    }
+
+    fn part1(&self, grid: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
         let word = "XMAS";
 
-        (0..grid.row_count)
+        Ok((0..grid.row_count)
             .map(|i| {
                 (0..grid.col_count)
                     .map(|j| {
-                        Self::check_horizontal(i, j, &grid, word)
-                            + Self::check_vertical(i, j, &grid, word)
-                            + Self::check_diagonal(i, j, &grid, word).len() as u128
+                        Self::check_horizontal(i, j, grid, word)
+                            + Self::check_vertical(i, j, grid, word)
+                            + Self::check_diagonal(i, j, grid, word).len() as u128
                     })
-                    .sum::<u128>() as u128
+                    .sum::<u128>()
             })
-            .sum()
+            .sum::<u128>()
+            .into())
     }
 
-    fn part2(&self, _input: &str) -> u128 {
-        let grid = Grid::from_string(_input);
-
-        (0..grid.row_count)
+    fn part2(&self, grid: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok((0..grid.row_count)
             .map(|i| {
                 (0..grid.col_count)
                     .filter(|j| {
-                        let as_diagonals = Self::check_diagonal(i, *j, &grid, "AS");
+                        let as_diagonals = Self::check_diagonal(i, *j, grid, "AS");
 
-                        let am_diagonals = Self::check_diagonal(i, *j, &grid, "AM");
+                        let am_diagonals = Self::check_diagonal(i, *j, grid, "AM");
 
                         as_diagonals.len() == 2
                             && am_diagonals.len() == 2
                             && (as_diagonals != HashSet::from([UpLeft, DownRight]))
                             && (as_diagonals != HashSet::from([UpRight, DownLeft]))
                     })
-                    .count() as u128
+                    .count()
             })
-            .sum::<u128>()
+            .sum::<usize>()
+            .into())
     }
 }
 
@@ -152,12 +161,13 @@ impl Problem4 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
     const P: Problem4 = Problem4 {};
 
     #[test]
     fn should_solve_part_1_example() {
         assert_eq!(
-            P.part1("\
+            P.solve(Part::One, "\
             MMMSXXMASM\n\
             MSAMXMSMSA\n\
             AMXSXMAAMM\n\
@@ -169,112 +179,112 @@ mod tests {
             MAMMMXMMMM\n\
             MXMXAXMASX\
             "),
-            18
+            Ok(Answer::from(18))
         );
     }
 
     #[test]
     fn should_find_forward_horizontal() {
         assert_eq!(
-            P.part1("\
+            P.solve(Part::One, "\
             MMMXMASXXX\
             "),
-            1
+            Ok(Answer::from(1))
         );
     }
 
     #[test]
     fn should_find_backward_horizontal() {
         assert_eq!(
-            P.part1("\
+            P.solve(Part::One, "\
             MMMMMSAMXXXXXX\
             "),
-            1
+            Ok(Answer::from(1))
         );
     }
 
     #[test]
     fn should_find_forward_vertical() {
         assert_eq!(
-            P.part1("\
+            P.solve(Part::One, "\
             MMMMMXMMMMM\n\
             MMMMMMMMMMM\n\
             MMMMMAMMMMM\n\
             MMMMMSMMMMM\
             "),
-            1
+            Ok(Answer::from(1))
         );
     }
 
     #[test]
     fn should_find_backward_vertical() {
         assert_eq!(
-            P.part1("\
+            P.solve(Part::One, "\
             MMMMMSMMMMM\n\
             MMMMMAMMMMM\n\
             MMMMMMMMMMM\n\
             MMMMMXMMMMM\
             "),
-            1
+            Ok(Answer::from(1))
         );
     }
 
     #[test]
     fn should_find_forward_negative_diagonal() {
         assert_eq!(
-            P.part1("\
+            P.solve(Part::One, "\
             MMMXMMMMMMM\n\
             MMMMMMMMMMM\n\
             MMMMMAMMMMM\n\
             MMMMMMSMMMM\
             "),
-            1
+            Ok(Answer::from(1))
         );
     }
 
     #[test]
     fn should_find_backward_negative_diagonal() {
         assert_eq!(
-            P.part1("\
+            P.solve(Part::One, "\
             MMMSMMMMMMM\n\
             MMMMAMMMMMM\n\
             MMMMMMMMMMM\n\
             MMMMMMXMMMM\
             "),
-            1
+            Ok(Answer::from(1))
         );
     }
 
     #[test]
     fn should_find_forward_positive_diagonal() {
         assert_eq!(
-            P.part1("\
+            P.solve(Part::One, "\
             MMMMMSMMMMM\n\
             MMMMAMMMMMM\n\
             MMMMMMMMMMM\n\
             MMXMMMMMMMM\
             "),
-            1
+            Ok(Answer::from(1))
         );
     }
 
     #[test]
     fn should_find_backward_positive_diagonal() {
         assert_eq!(
-            P.part1("\
+            P.solve(Part::One, "\
             MMMMMXMMMMM\n\
             MMMMMMMMMMM\n\
             MMMAMMMMMMM\n\
             MMSMMMMMMMM\
             "),
-            1
+            Ok(Answer::from(1))
         );
     }
 
     #[test]
     fn should_solve_part_2_example() {
         assert_eq!(
-            P.part2("\
+            P.solve(Part::Two, "\
             MMMSXXMASM\n\
             MSAMXMSMSA\n\
             AMXSXMAAMM\n\
@@ -286,26 +296,34 @@ mod tests {
             MAMMMXMMMM\n\
             MXMXAXMASX\
             "),
-            9
+            Ok(Answer::from(9))
         );
     }
 
     #[test]
     fn should_correctly_identify_x_mas() {
         assert_eq!(
-            P.part2("\
+            P.solve(Part::Two, "\
             MAS\n\
             AAA\n\
             SAM"),
-            0
+            Ok(Answer::from(0))
         );
 
         assert_eq!(
-            P.part2("\
+            P.solve(Part::Two, "\
             MAS\n\
             AAA\n\
             MAS"),
-            1
+            Ok(Answer::from(1))
+        )
+    }
+
+    #[test]
+    fn should_reject_empty_input() {
+        assert_eq!(
+            P.solve(Part::One, ""),
+            Err(ProblemError::parse(1, 1, "expected a grid"))
         )
     }
 }
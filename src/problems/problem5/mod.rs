@@ -1,106 +1,102 @@
-use crate::display::AppDisplayState;
-use crate::problems::Problem;
-use crate::Event;
-use crate::Event::{NewRowEvent, UpdateAppDisplayState};
+use crate::problems::common::parse_field;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use itertools::Itertools;
 use ratatui::style::Stylize;
 use ratatui::text::{Line, Span};
 use std::cmp::Ordering;
-use std::sync::mpsc::Sender;
 
-pub struct Problem5 {
-    tx: Sender<Event>,
-}
+pub struct Problem5 {}
+
+impl Problem for Problem5 {
+    const DAY: usize = 5;
+    const TITLE: &'static str = "Print Queue";
+    const TAGS: &'static [Tag] = &[Tag::Graph, Tag::Sorting];
+    const VISUALISED: bool = true;
+    type Parsed = (Vec<Rule>, Vec<Update>);
 
-impl Problem<u128> for Problem5 {
-    fn part1(&self, input: &str) -> u128 {
-        let (rules, updates) = Self::parse(input.trim());
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        Self::parse_rules_and_updates(input.trim_end())
+    }
 
-        Problem5::sort_updates(&updates, &rules).iter().fold(
+    fn part1(&self, (rules, updates): &Self::Parsed, ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        let total = Problem5::sort_updates(updates, rules).iter().fold(
             0u128,
-            |total_so_far, (&ref unsorted, sorted)| {
-                self.tx
-                    .send(UpdateAppDisplayState(AppDisplayState::part_1_only(
-                        total_so_far,
-                    )))
-                    .unwrap_or_else(|e| println!("Error updating UI {:?}", e));
-                if *unsorted == *sorted {
-                    self.tx
-                        .send(NewRowEvent(Line::from(format!("{:?}", unsorted)).green()))
-                        .unwrap_or_else(|e| println!("Error updating UI {:?}", e));
+            |total_so_far, (unsorted, sorted)| {
+                ctx.partial_answer(total_so_far);
+                if **unsorted == *sorted {
+                    ctx.log_line(Line::from(format!("{:?}", unsorted)).green());
                     total_so_far + unsorted.0[unsorted.0.len() / 2] as u128
                 } else {
-                    self.tx
-                        .send(NewRowEvent(Line::from(format!("{:?}", unsorted)).red()))
-                        .unwrap_or_else(|e| println!("Error updating UI {:?}", e));
+                    ctx.log_line(Line::from(format!("{:?}", unsorted)).red());
                     total_so_far
                 }
             },
-        )
-    }
+        );
 
-    fn part2(&self, input: &str) -> u128 {
-        let (rules, updates) = Self::parse(input.trim());
+        Ok(total.into())
+    }
 
-        Problem5::sort_updates(&updates, &rules).iter().fold(
+    fn part2(&self, (rules, updates): &Self::Parsed, ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        let total = Problem5::sort_updates(updates, rules).iter().fold(
             0u128,
             |total_so_far, (unsorted, sorted)| {
-                self.tx
-                    .send(UpdateAppDisplayState(AppDisplayState::part_2_only(
-                        total_so_far,
-                    )))
-                    .unwrap_or_else(|e| println!("Error updating UI {:?}", e));
+                ctx.partial_answer(total_so_far);
 
                 if *unsorted == sorted {
-                    self.tx
-                        .send(NewRowEvent(Line::from(format!("{:?}", unsorted)).gray()))
-                        .unwrap_or_else(|e| println!("Error updating UI {:?}", e));
+                    ctx.log_line(Line::from(format!("{:?}", unsorted)).gray());
                     total_so_far
                 } else {
-                    self.tx
-                        .send(NewRowEvent(Line::from(vec![
-                            Span::from(format!("{:?}", unsorted)).red(),
-                            Span::from(" -> "),
-                            Span::from(format!("{:?}", sorted)).green(),
-                        ])))
-                        .unwrap_or_else(|e| println!("Error updating UI {:?}", e));
+                    ctx.log_line(Line::from(vec![
+                        Span::from(format!("{:?}", unsorted)).red(),
+                        Span::from(" -> "),
+                        Span::from(format!("{:?}", sorted)).green(),
+                    ]));
                     total_so_far + sorted.0[sorted.0.len() / 2] as u128
                 }
             },
-        )
+        );
+
+        Ok(total.into())
     }
 }
 
 impl<'a> Problem5 {
-    pub fn new(tx: &'a Sender<Event>) -> Self {
-        Problem5 { tx: tx.clone() }
-    }
-
-    fn parse(data: &str) -> (Vec<Rule>, Vec<Update>) {
+    fn parse_rules_and_updates(data: &str) -> Result<(Vec<Rule>, Vec<Update>), ProblemError> {
         if let Some((l, r)) = data.split_once("\n\n") {
-            (
+            let first_update_line = l.lines().count() + 2;
+
+            Ok((
                 l.lines()
-                    .filter_map(|l| match l.split_once("|") {
-                        Some((l, r)) => Some(Rule(l.parse().unwrap(), r.parse().unwrap())),
-                        None => None,
+                    .enumerate()
+                    .filter_map(|(i, line)| {
+                        line.split_once("|").map(|(l, r)| {
+                            Ok(Rule(parse_field(i + 1, line, l)?, parse_field(i + 1, line, r)?))
+                        })
                     })
-                    .collect(),
+                    .collect::<Result<_, _>>()?,
                 r.lines()
-                    .map(|l| Update(l.split(",").map(|ui| ui.parse::<u32>().unwrap()).collect()))
-                    .collect(),
-            )
+                    .enumerate()
+                    .map(|(i, line)| {
+                        line.split(",")
+                            .map(|ui| parse_field(first_update_line + i, line, ui))
+                            .collect::<Result<_, _>>()
+                            .map(Update)
+                    })
+                    .collect::<Result<_, _>>()?,
+            ))
         } else {
-            (vec![], vec![])
+            Ok((vec![], vec![]))
         }
     }
 
-    fn sort_updates(updates: &'a Vec<Update>, rules: &'a Vec<Rule>) -> Vec<(&'a Update, Update)> {
+    fn sort_updates(updates: &'a [Update], rules: &'a [Rule]) -> Vec<(&'a Update, Update)> {
         updates
             .iter()
             .map(|u| {
                 let new_vec =
-                    u.0.to_vec()
-                        .into_iter()
+                    u.0.iter()
+                        .copied()
                         .sorted_by(|&l, &r| {
                             let applicable_rule = rules.iter().find(|&rule| {
                                 (rule.0 == l && rule.1 == r) || (rule.1 == l && rule.0 == r)
@@ -121,16 +117,15 @@ impl<'a> Problem5 {
 }
 
 #[derive(Debug, PartialEq)]
-struct Rule(u32, u32);
+pub struct Rule(u32, u32);
 
 #[derive(Debug, PartialEq)]
-
-struct Update(Vec<u32>);
+pub struct Update(Vec<u32>);
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::mpsc;
+    use crate::Part;
 
     fn load_sample_data() -> &'static str {
         include_str!("../../../sample_data/5.txt")
@@ -150,7 +145,7 @@ mod tests {
             2,9,8\n\
             3,10,2";
 
-            let (rules, updates) = Problem5::parse(data);
+            let (rules, updates) = Problem5::parse_rules_and_updates(data).unwrap();
 
             assert_eq!(rules, vec![Rule(1, 2), Rule(3, 4), Rule(5, 6)]);
             assert_eq!(
@@ -166,15 +161,15 @@ mod tests {
 
     #[test]
     fn should_produce_correct_answer_for_part_1() {
-        let p: Problem5 = Problem5::new(&mpsc::channel().0);
+        let p = Problem5 {};
 
-        assert_eq!(p.part1(load_sample_data()), 143);
+        assert_eq!(p.solve(Part::One, load_sample_data()), Ok(Answer::from(143)));
     }
 
     #[test]
     fn should_produce_correct_answer_for_part_2() {
-        let p: Problem5 = Problem5::new(&mpsc::channel().0);
+        let p = Problem5 {};
 
-        assert_eq!(p.part2(load_sample_data()), 123);
+        assert_eq!(p.solve(Part::Two, load_sample_data()), Ok(Answer::from(123)));
     }
 }
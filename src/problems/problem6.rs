@@ -1,15 +1,13 @@
 use crate::problems::common::Grid;
 use crate::problems::problem6::Direction::{EAST, NORTH, SOUTH, WEST};
-use crate::problems::Problem;
-use crate::Event;
+use crate::problems::{Problem, ProblemError, Runtime, SolveContext, Tag};
+use crate::Answer;
 use std::collections::HashSet;
-use std::sync::mpsc;
 
-pub struct Problem6 {
-    _tx: mpsc::Sender<Event>,
-}
+pub struct Problem6 {}
 
 #[derive(Clone, PartialEq, Eq, Hash, Copy)]
+#[allow(clippy::upper_case_acronyms)]
 enum Direction {
     NORTH,
     EAST,
@@ -18,7 +16,7 @@ enum Direction {
 }
 
 #[derive(Clone)]
-struct GuardGrid {
+pub struct GuardGrid {
     grid: Grid<char>,
     guard_position: (i32, i32),
     guard_direction: Direction,
@@ -30,8 +28,8 @@ struct GuardGrid {
 }
 
 impl GuardGrid {
-    fn from_string(s: &str) -> GuardGrid {
-        let grid = Grid::from_string(s);
+    fn from_string(s: &str) -> Result<GuardGrid, ProblemError> {
+        let grid = Grid::from_string(s)?;
 
         let maybe_guard_details = grid
             .lines
@@ -50,12 +48,14 @@ impl GuardGrid {
                 })
             })
             .collect::<Vec<((i32, i32), Direction)>>();
-        let (guard_position, guard_direction) = maybe_guard_details.get(0).unwrap();
-        let visited_positions: HashSet<(i32, i32)> = HashSet::from([guard_position.clone()]);
+        let (guard_position, guard_direction) = maybe_guard_details
+            .first()
+            .ok_or_else(|| ProblemError::parse(1, 1, "there is no guard on the map"))?;
+        let visited_positions: HashSet<(i32, i32)> = HashSet::from([*guard_position]);
         let visited_states: HashSet<(i32, i32, Direction)> = HashSet::from([(
-            guard_position.0.clone(),
-            guard_position.1.clone(),
-            guard_direction.clone(),
+            guard_position.0,
+            guard_position.1,
+            *guard_direction,
         )]);
 
         let obstacle_positions = grid
@@ -73,20 +73,20 @@ impl GuardGrid {
             })
             .collect::<HashSet<(i32, i32)>>();
 
-        GuardGrid {
+        Ok(GuardGrid {
             grid: Grid {
                 lines: vec![],
                 row_count: grid.row_count,
                 col_count: grid.col_count,
             },
-            guard_position: guard_position.clone(),
-            guard_direction: guard_direction.clone(),
+            guard_position: *guard_position,
+            guard_direction: *guard_direction,
             obstacle_positions,
             visited_positions,
             has_guard: true,
             has_looped: false,
             visited_states,
-        }
+        })
     }
 
     fn advance(&self) -> GuardGrid {
@@ -141,7 +141,7 @@ impl GuardGrid {
             && guard_position.1 < self.grid.col_count as i32;
         let mut visited_positions = self.visited_positions.clone();
         let mut visited_states = self.visited_states.clone();
-        let new_state = (guard_position.0, guard_position.1, guard_direction.clone());
+        let new_state = (guard_position.0, guard_position.1, guard_direction);
         let has_looped = self.visited_states.contains(&new_state);
 
         if has_guard {
@@ -172,22 +172,33 @@ impl GuardGrid {
     }
 }
 
-impl Problem<u128> for Problem6 {
-    fn part1(&self, input: &str) -> u128 {
+impl Problem for Problem6 {
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    const RUNTIME: Runtime = Runtime::Slow;
+    type Parsed = GuardGrid;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
         GuardGrid::from_string(input)
+    }
+
+    fn part1(&self, original_grid: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(original_grid
             .advance_all()
             .visited_positions
-            .len() as u128
+            .len()
+            .into())
     }
 
-    fn part2(&self, input: &str) -> u128 {
-        let original_grid = GuardGrid::from_string(input);
+    fn part2(&self, original_grid: &Self::Parsed, ctx: &SolveContext) -> Result<Answer, ProblemError> {
         let visited_positions = original_grid.advance_all().visited_positions;
 
-        visited_positions
+        Ok(visited_positions
             .iter()
-            .filter(|&visited_position| {
-                println!("visited position: {:?}", visited_position);
+            .enumerate()
+            .filter(|&(i, visited_position)| {
+                ctx.progress(i as f64 / visited_positions.len() as f64);
 
                 let mut new_obstacle_positions = original_grid.obstacle_positions.clone();
                 new_obstacle_positions.insert(*visited_position);
@@ -198,26 +209,23 @@ impl Problem<u128> for Problem6 {
 
                 grid_with_additional_obstacle.advance_all().has_looped
             })
-            .count() as u128
-    }
-}
-
-impl Problem6 {
-    pub fn new(tx: &mpsc::Sender<Event>) -> Problem6 {
-        Problem6 { _tx: tx.clone() }
+            .count()
+            .into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
 
     #[test]
     fn should_produce_correct_answer_for_part_1() {
-        let p: Problem6 = Problem6::new(&mpsc::channel().0);
+        let p = Problem6 {};
 
         assert_eq!(
-            p.part1(
+            p.solve(
+                Part::One,
                 "\
         ....#.....\n\
         .........#\n\
@@ -230,16 +238,17 @@ mod tests {
         #.........\n\
         ......#..."
             ),
-            41
+            Ok(Answer::from(41))
         );
     }
 
     #[test]
     fn should_produce_correct_answer_for_part_2() {
-        let p: Problem6 = Problem6::new(&mpsc::channel().0);
+        let p = Problem6 {};
 
         assert_eq!(
-            p.part2(
+            p.solve(
+                Part::Two,
                 "\
         ....#.....\n\
         .........#\n\
@@ -252,7 +261,7 @@ mod tests {
         #.........\n\
         ......#..."
             ),
-            6
+            Ok(Answer::from(6))
         );
     }
 }
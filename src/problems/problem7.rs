@@ -1,11 +1,14 @@
 use crate::problems::problem7::Operator::{ADD, CONCAT, MULTIPLY};
-use crate::problems::Problem;
+use crate::problems::common::parse_field;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use itertools::Itertools;
 use std::iter::repeat_n;
 
 pub struct Problem7 {}
 
-enum Operator {
+#[allow(clippy::upper_case_acronyms)]
+pub enum Operator {
     ADD,
     MULTIPLY,
     CONCAT,
@@ -21,44 +24,55 @@ impl Operator {
     }
 }
 
-struct Equation {
+pub struct Equation {
     test_value: u64,
     operands: Vec<u64>,
 }
 
 impl Equation {
-    fn parse(line: &str) -> Self {
-        let (test_value_str, operands_str) = line.split_once(": ").unwrap();
+    fn parse(line_number: usize, line: &str) -> Result<Self, ProblemError> {
+        let (test_value_str, operands_str) = line.split_once(": ").ok_or_else(|| {
+            ProblemError::parse(line_number, 1, "expected a test value followed by \": \"")
+        })?;
 
-        let operands: Vec<u64> = operands_str
+        let operands = operands_str
             .split(" ")
-            .map(|x| x.parse().unwrap())
-            .collect();
+            .map(|x| parse_field(line_number, line, x))
+            .collect::<Result<Vec<u64>, _>>()?;
 
-        Equation {
-            test_value: test_value_str.parse().unwrap(),
+        Ok(Equation {
+            test_value: parse_field(line_number, line, test_value_str)?,
             operands,
-        }
+        })
     }
 }
 
-impl Problem<u128> for Problem7 {
-    fn part1(&self, input: &str) -> u128 {
-        self.solve(input, &vec![ADD, MULTIPLY])
+impl Problem for Problem7 {
+    const DAY: usize = 7;
+    const TITLE: &'static str = "Bridge Repair";
+    const TAGS: &'static [Tag] = &[Tag::Math, Tag::Search];
+    type Parsed = Vec<Equation>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Equation::parse(i + 1, line))
+            .collect()
     }
 
-    fn part2(&self, input: &str) -> u128 {
-        self.solve(input, &vec![ADD, MULTIPLY, CONCAT])
+    fn part1(&self, equations: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(self.total_calibration(equations, &[ADD, MULTIPLY]).into())
     }
-}
 
-impl Problem7 {
-    fn parse(input: &str) -> Vec<Equation> {
-        input.lines().map(|line| Equation::parse(line)).collect()
+    fn part2(&self, equations: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(self.total_calibration(equations, &[ADD, MULTIPLY, CONCAT]).into())
     }
+}
 
-    fn solve(&self, input: &str, operators: &Vec<Operator>) -> u128 {
-        Problem7::parse(input)
+impl Problem7 {
+    fn total_calibration(&self, equations: &[Equation], operators: &[Operator]) -> u128 {
+        equations
             .iter()
             .filter_map(|eq| {
                 repeat_n(operators, eq.operands.len() - 1)
@@ -79,12 +93,14 @@ impl Problem7 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
 
     #[test]
     fn should_produce_correct_answer_for_part_1() {
         let p = Problem7 {};
         assert_eq!(
-            p.part1(
+            p.solve(
+                Part::One,
                 "\
                 190: 10 19\n\
                 3267: 81 40 27\n\
@@ -96,7 +112,7 @@ mod tests {
                 21037: 9 7 18 13\n\
                 292: 11 6 16 20"
             ),
-            3749
+            Ok(Answer::from(3749))
         );
     }
 
@@ -104,7 +120,8 @@ mod tests {
     fn should_produce_correct_answer_for_part_2() {
         let p = Problem7 {};
         assert_eq!(
-            p.part2(
+            p.solve(
+                Part::Two,
                 "\
                 190: 10 19\n\
                 3267: 81 40 27\n\
@@ -116,7 +133,21 @@ mod tests {
                 21037: 9 7 18 13\n\
                 292: 11 6 16 20"
             ),
-            11387
+            Ok(Answer::from(11387))
+        );
+    }
+
+    #[test]
+    fn should_report_where_input_is_invalid() {
+        let p = Problem7 {};
+        assert_eq!(
+            p.solve(Part::One, "190: 10 19
+3267: 81 4O 27"),
+            Err(ProblemError::parse(2, 10, "\"4O\": invalid digit found in string"))
+        );
+        assert_eq!(
+            p.solve(Part::One, "190 10 19"),
+            Err(ProblemError::parse(1, 1, "expected a test value followed by \": \""))
         );
     }
 }
@@ -1,20 +1,28 @@
 use crate::problems::common::Grid;
-use crate::problems::Problem;
+use crate::problems::{Problem, ProblemError, SolveContext, Tag};
+use crate::Answer;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
 pub struct Problem8 {}
 
-impl Problem<u128> for Problem8 {
-    fn part1(&self, input: &str) -> u128 {
-        let grid = Grid::from_string(input);
+impl Problem for Problem8 {
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Math];
+    type Parsed = Grid<char>;
 
-        let frequencies_and_locations = Problem8::find_frequencies_and_locations(&grid);
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        Grid::from_string(input)
+    }
 
-        frequencies_and_locations
-            .iter()
-            .flat_map(|(_, freq)| Problem8::find_pairs(freq))
-            .flat_map(|pair| Problem8::find_antinodes(pair))
+    fn part1(&self, grid: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        let frequencies_and_locations = Problem8::find_frequencies_and_locations(grid);
+
+        Ok(frequencies_and_locations
+            .values()
+            .flat_map(|locations| Problem8::find_pairs(locations))
+            .flat_map(Problem8::find_antinodes)
             .filter(|antinode| {
                 antinode.0 >= 0
                     && antinode.1 >= 0
@@ -23,21 +31,21 @@ impl Problem<u128> for Problem8 {
             })
             .sorted()
             .dedup()
-            .count() as u128
+            .count()
+            .into())
     }
 
-    fn part2(&self, input: &str) -> u128 {
-        let grid = Grid::from_string(input);
-
-        let frequencies_and_locations = Problem8::find_frequencies_and_locations(&grid);
+    fn part2(&self, grid: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        let frequencies_and_locations = Problem8::find_frequencies_and_locations(grid);
 
-        frequencies_and_locations
-            .iter()
-            .flat_map(|(_, freq)| Problem8::find_pairs(freq))
-            .flat_map(|pair| Problem8::find_harmonic_antinodes(pair, &grid))
+        Ok(frequencies_and_locations
+            .values()
+            .flat_map(|locations| Problem8::find_pairs(locations))
+            .flat_map(|pair| Problem8::find_harmonic_antinodes(pair, grid))
             .sorted()
             .dedup()
-            .count() as u128
+            .count()
+            .into())
     }
 }
 
@@ -56,7 +64,7 @@ impl Problem8 {
             })
     }
 
-    fn find_pairs(locations: &Vec<(i32, i32)>) -> Vec<((i32, i32), (i32, i32))> {
+    fn find_pairs(locations: &[(i32, i32)]) -> Vec<((i32, i32), (i32, i32))> {
         locations
             .iter()
             .combinations(2)
@@ -114,12 +122,14 @@ impl Problem8 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
 
     #[test]
     fn should_produce_correct_answer_for_part_1() {
         let p = Problem8 {};
         assert_eq!(
-            p.part1(
+            p.solve(
+                Part::One,
                 "\
                 ............\n\
                 ........0...\n\
@@ -134,7 +144,7 @@ mod tests {
                 ............\n\
                 ............"
             ),
-            14
+            Ok(Answer::from(14))
         );
     }
 
@@ -142,7 +152,8 @@ mod tests {
     fn should_produce_correct_answer_for_part_2() {
         let p = Problem8 {};
         assert_eq!(
-            p.part2(
+            p.solve(
+                Part::Two,
                 "\
                 ............\n\
                 ........0...\n\
@@ -157,7 +168,7 @@ mod tests {
                 ............\n\
                 ............"
             ),
-            34
+            Ok(Answer::from(34))
         );
     }
 
@@ -172,7 +183,8 @@ mod tests {
             ...A...\n\
             .B.B...\n\
             .......",
-            );
+            )
+            .unwrap();
 
             let expected = HashMap::from([('A', vec![(0, 3)]), ('B', vec![(1, 1), (1, 3)])]);
             assert_eq!(Problem8::find_frequencies_and_locations(&grid), expected);
@@ -180,18 +192,18 @@ mod tests {
 
         #[test]
         fn find_pairs_should_find_all_possible_combinations_of_two_frequency_locations() {
-            assert_eq!(Problem8::find_pairs(&vec![]), vec![]);
-            assert_eq!(Problem8::find_pairs(&vec![(0, 0)]), vec![]);
+            assert_eq!(Problem8::find_pairs(&[]), vec![]);
+            assert_eq!(Problem8::find_pairs(&[(0, 0)]), vec![]);
             assert_eq!(
-                Problem8::find_pairs(&vec![(0, 0), (2, 2)]),
+                Problem8::find_pairs(&[(0, 0), (2, 2)]),
                 vec![((0, 0), (2, 2))]
             );
             assert_eq!(
-                Problem8::find_pairs(&vec![(0, 0), (2, 2), (4, 4)]),
+                Problem8::find_pairs(&[(0, 0), (2, 2), (4, 4)]),
                 vec![((0, 0), (2, 2)), ((0, 0), (4, 4)), ((2, 2), (4, 4)),]
             );
             assert_eq!(
-                Problem8::find_pairs(&vec![(0, 0), (2, 2), (4, 4), (6, 6)]),
+                Problem8::find_pairs(&[(0, 0), (2, 2), (4, 4), (6, 6)]),
                 vec![
                     ((0, 0), (2, 2)),
                     ((0, 0), (4, 4)),
@@ -1,17 +1,18 @@
-use crate::problems::Problem;
+use crate::problems::{Problem, ProblemError, Runtime, SolveContext, Tag};
+use crate::Answer;
 use itertools::Itertools;
 use std::ops::Range;
 
 pub struct Problem9 {}
 
 #[derive(PartialEq, Clone, Debug)]
-struct File {
+pub struct File {
     id: u64,
     locations: Vec<u64>,
 }
 
 #[derive(PartialEq, Clone, Debug)]
-struct FileSystem {
+pub struct FileSystem {
     pub files: Vec<File>,
     pub free_spaces: Vec<Range<u64>>,
 }
@@ -71,11 +72,48 @@ impl FileSystem {
     }
 }
 
-impl Problem<u128> for Problem9 {
-    fn part1(&self, input: &str) -> u128 {
-        let mut file_system = Problem9::parse(input);
+impl Problem for Problem9 {
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+    const TAGS: &'static [Tag] = &[Tag::Simulation];
+    const RUNTIME: Runtime = Runtime::Slow;
+    type Parsed = FileSystem;
 
-        file_system
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ProblemError> {
+        Ok(input
+            .char_indices()
+            .filter(|(_, c)| *c != '\n')
+            .try_fold(
+                (
+                    FileSystem {
+                        files: vec![],
+                        free_spaces: vec![],
+                    },
+                    0u64,
+                ),
+                |(mut fs, fs_index), (i, c)| {
+                    let size = c
+                        .to_digit(10)
+                        .ok_or_else(|| ProblemError::parse(1, i + 1, format!("{c:?} is not a digit")))?
+                        as u64;
+                    let end_index = fs_index + size;
+                    if i % 2 == 0 {
+                        fs.files.push(File {
+                            id: i as u64 / 2,
+                            locations: (fs_index..end_index).collect_vec(),
+                        });
+                    } else {
+                        fs.free_spaces.push(fs_index..end_index);
+                    }
+                    Ok::<_, ProblemError>((fs, end_index))
+                },
+            )?
+            .0)
+    }
+
+    fn part1(&self, file_system: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(file_system
+            .clone()
             .compact()
             .files
             .iter()
@@ -86,13 +124,13 @@ impl Problem<u128> for Problem9 {
                     .map(|location| location * file.id)
                     .sum::<u64>()
             })
-            .sum::<u64>() as u128
+            .sum::<u64>()
+            .into())
     }
 
-    fn part2(&self, input: &str) -> u128 {
-        let mut file_system = Problem9::parse(input);
-
-        file_system
+    fn part2(&self, file_system: &Self::Parsed, _ctx: &SolveContext) -> Result<Answer, ProblemError> {
+        Ok(file_system
+            .clone()
             .compact_no_fragmentation()
             .files
             .iter()
@@ -103,56 +141,26 @@ impl Problem<u128> for Problem9 {
                     .map(|location| location * file.id)
                     .sum::<u64>()
             })
-            .sum::<u64>() as u128
-    }
-}
-
-impl Problem9 {
-    fn parse(input: &str) -> FileSystem {
-        input
-            .char_indices()
-            .filter(|(_, c)| *c != '\n')
-            .fold(
-                (
-                    FileSystem {
-                        files: vec![],
-                        free_spaces: vec![],
-                    },
-                    0u64,
-                ),
-                |(mut fs, fs_index), (i, c)| {
-                    let size = c.to_digit(10).unwrap() as u64;
-                    let end_index = fs_index + size;
-                    if i % 2 == 0 {
-                        fs.files.push(File {
-                            id: i as u64 / 2,
-                            locations: (fs_index..end_index).collect_vec(),
-                        });
-                        (fs, end_index)
-                    } else {
-                        fs.free_spaces.push(fs_index..end_index);
-                        (fs, end_index)
-                    }
-                },
-            )
-            .0
+            .sum::<u64>()
+            .into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Part;
 
     #[test]
     fn should_produce_correct_answer_for_part_1() {
         let p = Problem9 {};
-        assert_eq!(p.part1("2333133121414131402"), 1928);
+        assert_eq!(p.solve(Part::One, "2333133121414131402"), Ok(Answer::from(1928)));
     }
 
     #[test]
     fn should_produce_correct_answer_for_part_2() {
         let p = Problem9 {};
-        assert_eq!(p.part2("2333133121414131402"), 2858);
+        assert_eq!(p.solve(Part::Two, "2333133121414131402"), Ok(Answer::from(2858)));
     }
     // 00...111...2...333.44.5555.6666.777.888899
     mod test_helper_functions {
@@ -161,8 +169,8 @@ mod tests {
         #[test]
         fn should_parse_input() {
             assert_eq!(
-                Problem9::parse("12345\n"),
-                FileSystem {
+                Problem9 {}.parse("12345\n"),
+                Ok(FileSystem {
                     files: vec![
                         File {
                             id: 0,
@@ -178,7 +186,7 @@ mod tests {
                         },
                     ],
                     free_spaces: vec![1..3, 6..10],
-                }
+                })
             )
         }
 
@@ -257,7 +265,7 @@ mod tests {
                             locations: vec![1, 2]
                         },
                     ],
-                    free_spaces: vec![6..10],
+                    free_spaces: vec![Range { start: 6, end: 10 }],
                 }
             )
         }
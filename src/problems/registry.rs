@@ -0,0 +1,100 @@
+use crate::days_in_year;
+use crate::problems::AnyProblem;
+use std::collections::BTreeMap;
+
+/// Every problem solved for a year, looked up by the day each one declares
+pub struct Registry {
+    year: i32,
+    problems: BTreeMap<usize, Box<dyn AnyProblem>>,
+}
+
+impl Registry {
+    /// Fails if two problems claim the same day, or a problem claims a day the year doesn't have
+    pub fn new(year: i32, problems: Vec<Box<dyn AnyProblem>>) -> crate::Result<Self> {
+        let days = days_in_year(year) as usize;
+        let mut registry = BTreeMap::new();
+
+        for problem in problems {
+            let day = problem.day();
+
+            if !(1..=days).contains(&day) {
+                return Err(format!("A problem is registered for day {day}, but {year} only has {days} days").into());
+            }
+            if registry.insert(day, problem).is_some() {
+                return Err(format!("More than one problem is registered for {year} day {day}").into());
+            }
+        }
+
+        Ok(Registry {
+            year,
+            problems: registry,
+        })
+    }
+
+    pub fn get(&self, day: usize) -> Option<&dyn AnyProblem> {
+        self.problems.get(&day).map(|problem| &**problem)
+    }
+
+    /// Every day of the year in order, with its problem if one has been written
+    pub fn days(&self) -> impl Iterator<Item = (usize, Option<&dyn AnyProblem>)> {
+        (1..=days_in_year(self.year) as usize).map(|day| (day, self.get(day)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problems::{Problem, ProblemError};
+
+    struct Day<const N: usize>;
+
+    impl<const N: usize> Problem for Day<N> {
+        const DAY: usize = N;
+        const TITLE: &'static str = "Test";
+        type Parsed = ();
+
+        fn parse(&self, _input: &str) -> Result<Self::Parsed, ProblemError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn should_look_problems_up_by_their_declared_day() {
+        let registry = Registry::new(2024, vec![Box::new(Day::<3>), Box::new(Day::<1>)]).unwrap();
+
+        assert_eq!(registry.get(3).map(|p| p.day()), Some(3));
+        assert!(registry.get(2).is_none());
+        assert_eq!(
+            registry
+                .days()
+                .filter(|(_, problem)| problem.is_some())
+                .map(|(day, _)| day)
+                .collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn should_reject_days_registered_twice() {
+        let error = Registry::new(2024, vec![Box::new(Day::<4>), Box::new(Day::<4>)])
+            .err()
+            .unwrap();
+
+        assert_eq!(error.to_string(), "More than one problem is registered for 2024 day 4");
+    }
+
+    #[test]
+    fn should_reject_days_outside_the_year() {
+        let error = Registry::new(2025, vec![Box::new(Day::<13>)]).err().unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "A problem is registered for day 13, but 2025 only has 12 days"
+        );
+    }
+
+    #[test]
+    fn should_register_every_problem_once() {
+        assert!(crate::problems::problem_registry(crate::DEFAULT_YEAR).is_ok());
+    }
+}
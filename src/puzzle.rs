@@ -0,0 +1,142 @@
+use regex::Regex;
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub description: String,
+    pub examples: Vec<String>,
+}
+
+impl Puzzle {
+    pub fn parse(page: &str) -> Option<Puzzle> {
+        let article_regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+        let example_regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+
+        let articles = article_regex
+            .captures_iter(page)
+            .map(|c| c.extract::<1>().1[0])
+            .collect::<Vec<_>>();
+
+        if articles.is_empty() {
+            return None;
+        }
+
+        Some(Puzzle {
+            description: articles
+                .iter()
+                .map(|article| html_to_text(article))
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            examples: articles
+                .iter()
+                .flat_map(|article| example_regex.captures_iter(article))
+                .map(|c| decode_entities(&strip_tags(c.extract::<1>().1[0])))
+                .collect(),
+        })
+    }
+
+    pub fn save(&self, directory: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(directory)?;
+
+        for (i, example) in self.examples.iter().enumerate() {
+            std::fs::write(directory.join(format!("example_{}.txt", i + 1)), example)?;
+        }
+
+        // Written last, since its presence is what marks the puzzle as already fetched
+        std::fs::write(directory.join("description.txt"), &self.description)
+    }
+}
+
+fn html_to_text(html: &str) -> String {
+    let block_end_regex = Regex::new(r"</(h2|p|pre|ul|li)>\s*").unwrap();
+    let html = html.replace("<li>", "- ");
+    let text = block_end_regex.replace_all(&html, |c: &regex::Captures| match &c[1] {
+        "li" => "\n",
+        _ => "\n\n",
+    });
+
+    let blank_lines_regex = Regex::new(r"\n{3,}").unwrap();
+
+    blank_lines_regex
+        .replace_all(decode_entities(&strip_tags(&text)).trim(), "\n\n")
+        .to_string()
+}
+
+fn strip_tags(html: &str) -> String {
+    let tag_regex = Regex::new(r"<[^>]*>").unwrap();
+
+    tag_regex.replace_all(html, "").to_string()
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_fixture() -> &'static str {
+        include_str!("fixtures/puzzle_page.html")
+    }
+
+    #[test]
+    fn should_extract_examples_from_both_parts() {
+        let puzzle = Puzzle::parse(load_fixture()).unwrap();
+
+        assert_eq!(
+            puzzle.examples,
+            vec![
+                String::from("3   4\n4   3\n2   5\n"),
+                String::from("3   4\n4   3\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_extract_article_text_without_markup() {
+        let puzzle = Puzzle::parse(load_fixture()).unwrap();
+
+        assert_eq!(
+            puzzle.description,
+            "\
+            --- Day 1: Counting Lanterns ---\n\
+            \n\
+            The elves have lined up their lanterns in two rows & want to know how far apart the rows are.\n\
+            \n\
+            For example:\n\
+            \n\
+            3   4\n\
+            4   3\n\
+            2   5\n\
+            \n\
+            Pair up the smallest numbers in each row:\n\
+            \n\
+            - The first pair is 2 and 3.\n\
+            - Numbers < 10 are written with one digit.\n\
+            \n\
+            What is the total distance between your lists?\n\
+            \n\
+            --- Part Two ---\n\
+            \n\
+            Now count how often each number appears:\n\
+            \n\
+            3   4\n\
+            4   3\n\
+            \n\
+            What is their similarity score?"
+        );
+    }
+
+    #[test]
+    fn should_not_parse_page_without_puzzle() {
+        assert_eq!(
+            Puzzle::parse("<html><body><main><p>Please log in</p></main></body></html>"),
+            None
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use crate::problems::{AnyProblem, PreparedProblem, ProblemError};
+use crate::table::{format_table, Align};
+use crate::{Answer, Part};
+use std::any::Any;
+use std::cell::Cell;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+pub enum PartOutcome {
+    Solved { answer: Answer, duration: Duration },
+    Unimplemented,
+    Failed(ProblemError),
+}
+
+pub struct SummaryRow {
+    pub day: usize,
+    pub part: Option<Part>,
+    pub outcome: RowOutcome,
+}
+
+pub enum RowOutcome {
+    /// How long the input took to parse, or why it couldn't be
+    Parse(Result<Duration, ProblemError>),
+    Part(PartOutcome),
+    MissingInput,
+    MissingSolution,
+}
+
+/// Parses the input for a problem once, ready for both of its parts to be solved from it
+pub fn run_parse<'a>(
+    problem: &'a dyn AnyProblem,
+    input: &str,
+) -> Result<(Box<dyn PreparedProblem + 'a>, Duration), ProblemError> {
+    let start = Instant::now();
+
+    catch_panics(|| problem.prepare(input)).map(|prepared| (prepared, start.elapsed()))
+}
+
+pub fn run_part<F>(solver: F) -> PartOutcome
+where
+    F: FnOnce() -> Result<Answer, ProblemError>,
+{
+    let start = Instant::now();
+
+    match catch_panics(solver) {
+        Ok(answer) => PartOutcome::Solved {
+            answer,
+            duration: start.elapsed(),
+        },
+        Err(ProblemError::Unimplemented) => PartOutcome::Unimplemented,
+        Err(e) => PartOutcome::Failed(e),
+    }
+}
+
+/// Solvers report problems through their result, but a stray unwrap shouldn't bring down the
+/// whole run
+fn catch_panics<T>(f: impl FnOnce() -> Result<T, ProblemError>) -> Result<T, ProblemError> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        Err(match panic_message(payload.as_ref()) {
+            m if m == "not yet implemented" => ProblemError::Unimplemented,
+            m => ProblemError::Failed(m),
+        })
+    })
+}
+
+thread_local! {
+    static PANICS_SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs, once for the whole process, a panic hook that stays quiet on threads inside
+/// [`with_silenced_panics`] and hands every other panic to the hook that was there before
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !PANICS_SILENCED.get() {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f` without printing the message for any panic on this thread. Other threads still
+/// report theirs
+pub fn with_silenced_panics<T>(f: impl FnOnce() -> T) -> T {
+    install_panic_hook();
+
+    let was_silenced = PANICS_SILENCED.replace(true);
+    let result = f();
+    PANICS_SILENCED.set(was_silenced);
+
+    result
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+pub fn format_summary(rows: &[SummaryRow]) -> String {
+    let cells = rows
+        .iter()
+        .map(|row| {
+            let part = match (&row.outcome, row.part) {
+                (RowOutcome::Parse(_), _) => String::from("parse"),
+                (_, Some(p)) => p.to_string(),
+                (_, None) => String::from("-"),
+            };
+            let (answer, time) = match &row.outcome {
+                RowOutcome::Parse(Ok(duration)) => (String::from("parsed"), format_duration(duration)),
+                RowOutcome::Parse(Err(ProblemError::Unimplemented)) => {
+                    (String::from("skipped (not implemented)"), String::from("-"))
+                }
+                RowOutcome::Parse(Err(e)) => (format!("failed ({e})"), String::from("-")),
+                RowOutcome::Part(PartOutcome::Solved { answer, duration }) => {
+                    (answer.to_string(), format_duration(duration))
+                }
+                RowOutcome::Part(PartOutcome::Unimplemented) => {
+                    (String::from("skipped (not implemented)"), String::from("-"))
+                }
+                RowOutcome::Part(PartOutcome::Failed(e)) => {
+                    (format!("failed ({e})"), String::from("-"))
+                }
+                RowOutcome::MissingInput => (String::from("skipped (no input)"), String::from("-")),
+                RowOutcome::MissingSolution => {
+                    (String::from("skipped (no solution)"), String::from("-"))
+                }
+            };
+
+            [row.day.to_string(), part, answer, time]
+        })
+        .collect::<Vec<_>>();
+
+    format_table(
+        ["Day", "Part", "Answer", "Time"],
+        [Align::Right, Align::Right, Align::Left, Align::Right],
+        &cells,
+    )
+}
+
+pub fn format_duration(duration: &Duration) -> String {
+    if duration.as_secs() > 0 {
+        format!("{:.2}s", duration.as_secs_f64())
+    } else {
+        format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_return_answer_for_successful_part() {
+        match run_part(|| Ok(Answer::from(42))) {
+            PartOutcome::Solved { answer, .. } => assert_eq!(answer, Answer::Integer(42)),
+            _ => panic!("Expected part to be solved"),
+        }
+    }
+
+    #[test]
+    fn should_treat_todo_as_unimplemented() {
+        let outcome = with_silenced_panics(|| run_part(|| todo!()));
+
+        assert!(matches!(outcome, PartOutcome::Unimplemented));
+    }
+
+    #[test]
+    fn should_treat_unimplemented_error_as_unimplemented() {
+        let outcome = run_part(|| Err(ProblemError::Unimplemented));
+
+        assert!(matches!(outcome, PartOutcome::Unimplemented));
+    }
+
+    #[test]
+    fn should_keep_error_returned_by_part() {
+        let outcome = run_part(|| Err(ProblemError::parse(2, 5, "expected a number")));
+
+        match outcome {
+            PartOutcome::Failed(e) => {
+                assert_eq!(e.to_string(), "parse error at line 2, column 5: expected a number")
+            }
+            _ => panic!("Expected part to have failed"),
+        }
+    }
+
+    #[test]
+    fn should_capture_panic_message() {
+        let outcome = with_silenced_panics(|| run_part(|| panic!("bad input on line {}", 3)));
+
+        match outcome {
+            PartOutcome::Failed(e) => assert_eq!(e, ProblemError::failed("bad input on line 3")),
+            _ => panic!("Expected part to have panicked"),
+        }
+    }
+
+    #[test]
+    fn should_capture_panic_while_parsing() {
+        struct Panicky;
+
+        impl crate::problems::Problem for Panicky {
+            const DAY: usize = 1;
+            const TITLE: &'static str = "Test";
+            type Parsed = ();
+
+            fn parse(&self, _input: &str) -> Result<Self::Parsed, ProblemError> {
+                panic!("unreadable input")
+            }
+        }
+
+        let outcome = with_silenced_panics(|| run_parse(&Panicky, "").map(|_| ()));
+
+        assert_eq!(outcome, Err(ProblemError::failed("unreadable input")));
+    }
+
+    #[test]
+    fn should_format_summary_as_aligned_table() {
+        let rows = vec![
+            SummaryRow {
+                day: 1,
+                part: None,
+                outcome: RowOutcome::Parse(Ok(Duration::from_micros(250))),
+            },
+            SummaryRow {
+                day: 1,
+                part: Some(Part::One),
+                outcome: RowOutcome::Part(PartOutcome::Solved {
+                    answer: Answer::from(1234),
+                    duration: Duration::from_millis(3),
+                }),
+            },
+            SummaryRow {
+                day: 12,
+                part: None,
+                outcome: RowOutcome::MissingInput,
+            },
+        ];
+
+        assert_eq!(
+            format_summary(&rows),
+            [
+                "Day   Part  Answer                Time",
+                "  1  parse  parsed              0.25ms",
+                "  1      1  1234                3.00ms",
+                " 12      -  skipped (no input)       -",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use crate::days_in_year;
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use std::time::Duration;
+
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+pub fn unlock_time(year: i32, problem_number: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, 12, problem_number, 5, 0, 0)
+        .unwrap()
+}
+
+pub fn next_unreleased_day(year: i32, now: DateTime<Utc>) -> Option<u32> {
+    (1..=days_in_year(year)).find(|&problem_number| unlock_time(year, problem_number) > now)
+}
+
+pub fn wait_until<F>(clock: &dyn Clock, target: DateTime<Utc>, mut on_tick: F)
+where
+    F: FnMut(TimeDelta),
+{
+    loop {
+        let remaining = target - clock.now();
+        if remaining <= TimeDelta::zero() {
+            return;
+        }
+
+        on_tick(remaining);
+
+        // Sleeping at most a second at a time keeps the countdown ticking and stops a suspended
+        // laptop from oversleeping the unlock
+        clock.sleep(
+            remaining
+                .to_std()
+                .unwrap_or_default()
+                .min(Duration::from_secs(1)),
+        );
+    }
+}
+
+pub fn format_countdown(remaining: TimeDelta) -> String {
+    let seconds = remaining.num_seconds().max(0);
+    let (days, hours, minutes, seconds) = (
+        seconds / 86400,
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    );
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    struct FakeClock {
+        now: Cell<DateTime<Utc>>,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl FakeClock {
+        fn at(now: DateTime<Utc>) -> FakeClock {
+            FakeClock {
+                now: Cell::new(now),
+                sleeps: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.sleeps.borrow_mut().push(duration);
+            self.now
+                .set(self.now.get() + TimeDelta::from_std(duration).unwrap());
+        }
+    }
+
+    mod next_unreleased_day {
+        use super::*;
+
+        #[test]
+        fn should_be_first_day_before_advent() {
+            let now = Utc.with_ymd_and_hms(2024, 11, 30, 12, 0, 0).unwrap();
+
+            assert_eq!(next_unreleased_day(2024, now), Some(1));
+        }
+
+        #[test]
+        fn should_be_tomorrow_once_todays_puzzle_is_out() {
+            let now = Utc.with_ymd_and_hms(2024, 12, 7, 5, 0, 0).unwrap();
+
+            assert_eq!(next_unreleased_day(2024, now), Some(8));
+        }
+
+        #[test]
+        fn should_be_today_before_unlock() {
+            let now = Utc.with_ymd_and_hms(2024, 12, 7, 4, 59, 59).unwrap();
+
+            assert_eq!(next_unreleased_day(2024, now), Some(7));
+        }
+
+        #[test]
+        fn should_stop_after_twelve_days_from_2025() {
+            let now = Utc.with_ymd_and_hms(2025, 12, 12, 5, 0, 1).unwrap();
+
+            assert_eq!(next_unreleased_day(2025, now), None);
+        }
+
+        #[test]
+        fn should_be_none_after_last_day() {
+            let now = Utc.with_ymd_and_hms(2024, 12, 25, 5, 0, 1).unwrap();
+
+            assert_eq!(next_unreleased_day(2024, now), None);
+        }
+    }
+
+    #[test]
+    fn should_sleep_until_target_in_steps_of_at_most_a_second() {
+        let clock = FakeClock::at(Utc.with_ymd_and_hms(2024, 12, 7, 4, 59, 57).unwrap());
+        let target = unlock_time(2024, 7);
+        let mut ticks = vec![];
+
+        wait_until(&clock, target, |remaining| {
+            ticks.push(remaining.num_seconds())
+        });
+
+        assert_eq!(clock.now(), target);
+        assert_eq!(ticks, vec![3, 2, 1]);
+        assert_eq!(*clock.sleeps.borrow(), vec![Duration::from_secs(1); 3]);
+    }
+
+    #[test]
+    fn should_not_sleep_when_target_has_passed() {
+        let clock = FakeClock::at(Utc.with_ymd_and_hms(2024, 12, 7, 6, 0, 0).unwrap());
+
+        wait_until(&clock, unlock_time(2024, 7), |_| panic!("Should not tick"));
+
+        assert!(clock.sleeps.borrow().is_empty());
+    }
+
+    #[test]
+    fn should_format_countdown() {
+        assert_eq!(format_countdown(TimeDelta::seconds(3_723)), "01:02:03");
+        assert_eq!(format_countdown(TimeDelta::seconds(90_061)), "1d 01:01:01");
+    }
+}
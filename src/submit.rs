@@ -0,0 +1,377 @@
+use crate::client::AocClient;
+use crate::Part;
+use regex::Regex;
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited(Option<String>),
+    AlreadySolved,
+    Unrecognised,
+}
+
+impl SubmissionOutcome {
+    fn from_label(label: &str) -> Option<SubmissionOutcome> {
+        match label {
+            "correct" => Some(SubmissionOutcome::Correct),
+            "wrong" => Some(SubmissionOutcome::Wrong),
+            "too-high" => Some(SubmissionOutcome::TooHigh),
+            "too-low" => Some(SubmissionOutcome::TooLow),
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        match self {
+            SubmissionOutcome::Correct => Some("correct"),
+            SubmissionOutcome::Wrong => Some("wrong"),
+            SubmissionOutcome::TooHigh => Some("too-high"),
+            SubmissionOutcome::TooLow => Some("too-low"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmissionOutcome::Wrong => write!(f, "That's not the right answer"),
+            SubmissionOutcome::TooHigh => write!(f, "That's not the right answer, it's too high"),
+            SubmissionOutcome::TooLow => write!(f, "That's not the right answer, it's too low"),
+            SubmissionOutcome::RateLimited(Some(wait)) => {
+                write!(f, "Answer submitted too recently, {wait} left to wait")
+            }
+            SubmissionOutcome::RateLimited(None) => write!(f, "Answer submitted too recently"),
+            SubmissionOutcome::AlreadySolved => {
+                write!(
+                    f,
+                    "This part has already been solved, or is not unlocked yet"
+                )
+            }
+            SubmissionOutcome::Unrecognised => write!(f, "Unrecognised response from server"),
+        }
+    }
+}
+
+pub fn parse_outcome(body: &str) -> SubmissionOutcome {
+    if body.contains("That's the right answer") {
+        SubmissionOutcome::Correct
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too high") {
+            SubmissionOutcome::TooHigh
+        } else if body.contains("your answer is too low") {
+            SubmissionOutcome::TooLow
+        } else {
+            SubmissionOutcome::Wrong
+        }
+    } else if body.contains("You gave an answer too recently") {
+        let wait_regex = Regex::new(r"You have ([^.]+) left to wait").unwrap();
+
+        SubmissionOutcome::RateLimited(
+            wait_regex
+                .captures(body)
+                .map(|c| c.extract::<1>().1[0].to_string()),
+        )
+    } else if body.contains("You don't seem to be solving the right level") {
+        SubmissionOutcome::AlreadySolved
+    } else {
+        SubmissionOutcome::Unrecognised
+    }
+}
+
+pub fn post_answer(
+    client: &AocClient,
+    year: i32,
+    day: usize,
+    part: Part,
+    answer: &str,
+) -> crate::Result<SubmissionOutcome> {
+    let body = client
+        .post_form(
+            &format!("/{year}/day/{day}/answer"),
+            &[("level", part.to_string()), ("answer", answer.to_string())],
+        )?
+        .error_for_status()?
+        .text()?;
+
+    Ok(parse_outcome(&body))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: usize,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+}
+
+#[derive(Debug, Default)]
+pub struct SubmissionLog {
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn load(path: &Path) -> crate::Result<SubmissionLog> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Ok(SubmissionLog::parse(&contents)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(SubmissionLog::default()),
+            Err(e) => Err(format!("Unable to read {}: {e}", path.display()).into()),
+        }
+    }
+
+    fn parse(contents: &str) -> SubmissionLog {
+        let submissions = contents
+            .lines()
+            .filter_map(|line| {
+                let [day, part, answer, outcome] = line.split('\t').collect::<Vec<_>>()[..] else {
+                    return None;
+                };
+
+                Some(Submission {
+                    day: day.parse().ok()?,
+                    part: match part {
+                        "1" => Part::One,
+                        "2" => Part::Two,
+                        _ => return None,
+                    },
+                    answer: answer.to_string(),
+                    outcome: SubmissionOutcome::from_label(outcome)?,
+                })
+            })
+            .collect();
+
+        SubmissionLog { submissions }
+    }
+
+    pub fn check(&self, day: usize, part: Part, answer: &str) -> Option<String> {
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .collect::<Vec<_>>();
+
+        if let Some(correct) = previous
+            .iter()
+            .find(|s| s.outcome == SubmissionOutcome::Correct)
+        {
+            return Some(format!(
+                "Day {day} part {part} was already solved with {}",
+                correct.answer
+            ));
+        }
+
+        if let Some(same) = previous.iter().find(|s| s.answer == answer) {
+            return Some(format!(
+                "{answer} was already submitted for day {day} part {part}: {}",
+                same.outcome
+            ));
+        }
+
+        let numeric_answer = answer.parse::<i128>().ok()?;
+        previous.iter().find_map(|s| {
+            let previous_answer = s.answer.parse::<i128>().ok()?;
+
+            match s.outcome {
+                SubmissionOutcome::TooHigh if numeric_answer >= previous_answer => Some(format!(
+                    "{answer} is not lower than {previous_answer}, which was already too high"
+                )),
+                SubmissionOutcome::TooLow if numeric_answer <= previous_answer => Some(format!(
+                    "{answer} is not higher than {previous_answer}, which was already too low"
+                )),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn record(&mut self, path: &Path, submission: Submission) -> crate::Result<()> {
+        if let Some(label) = submission.outcome.label() {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{label}",
+                submission.day, submission.part, submission.answer
+            )?;
+
+            self.submissions.push(submission);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_config;
+    use crate::test_server::TestServer;
+
+    fn wrap_in_page(message: &str) -> String {
+        format!("<!DOCTYPE html><html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    mod parse_outcome {
+        use super::*;
+
+        #[test]
+        fn should_recognise_correct_answer() {
+            assert_eq!(
+                parse_outcome(&wrap_in_page(
+                    "That's the right answer! You are one gold star closer."
+                )),
+                SubmissionOutcome::Correct
+            );
+        }
+
+        #[test]
+        fn should_recognise_too_high_and_too_low() {
+            assert_eq!(
+                parse_outcome(&wrap_in_page(
+                    "That's not the right answer; your answer is too high."
+                )),
+                SubmissionOutcome::TooHigh
+            );
+            assert_eq!(
+                parse_outcome(&wrap_in_page(
+                    "That's not the right answer; your answer is too low."
+                )),
+                SubmissionOutcome::TooLow
+            );
+        }
+
+        #[test]
+        fn should_recognise_wrong_answer_without_hint() {
+            assert_eq!(
+                parse_outcome(&wrap_in_page(
+                    "That's not the right answer. If you're stuck, ..."
+                )),
+                SubmissionOutcome::Wrong
+            );
+        }
+
+        #[test]
+        fn should_recognise_rate_limiting_and_extract_wait() {
+            assert_eq!(
+                parse_outcome(&wrap_in_page(
+                    "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait."
+                )),
+                SubmissionOutcome::RateLimited(Some(String::from("4m 2s")))
+            );
+        }
+
+        #[test]
+        fn should_recognise_already_solved_level() {
+            assert_eq!(
+                parse_outcome(&wrap_in_page(
+                    "You don't seem to be solving the right level.  Did you already complete it?"
+                )),
+                SubmissionOutcome::AlreadySolved
+            );
+        }
+    }
+
+    mod submission_log {
+        use super::*;
+
+        fn log() -> SubmissionLog {
+            SubmissionLog::parse(
+                "\
+                3\t1\t100\ttoo-high\n\
+                3\t1\t50\ttoo-low\n\
+                3\t1\tabc\twrong\n\
+                4\t2\t12\tcorrect\n",
+            )
+        }
+
+        #[test]
+        fn should_allow_new_answer_within_bounds() {
+            assert_eq!(log().check(3, Part::One, "75"), None);
+            assert_eq!(log().check(3, Part::Two, "100"), None);
+        }
+
+        #[test]
+        fn should_reject_repeated_wrong_answer() {
+            assert!(log().check(3, Part::One, "abc").is_some());
+        }
+
+        #[test]
+        fn should_reject_answers_outside_known_bounds() {
+            assert!(log().check(3, Part::One, "100").is_some());
+            assert!(log().check(3, Part::One, "150").is_some());
+            assert!(log().check(3, Part::One, "50").is_some());
+            assert!(log().check(3, Part::One, "10").is_some());
+        }
+
+        #[test]
+        fn should_reject_already_solved_part() {
+            assert!(log().check(4, Part::Two, "13").is_some());
+        }
+
+        #[test]
+        fn should_only_record_evaluated_submissions() {
+            let path = std::env::temp_dir().join(format!("submissions-{}.tsv", std::process::id()));
+            let _ = std::fs::remove_file(&path);
+            let mut log = SubmissionLog::load(&path).unwrap();
+
+            for (answer, outcome) in [
+                ("10", SubmissionOutcome::TooLow),
+                ("20", SubmissionOutcome::RateLimited(None)),
+            ] {
+                log.record(
+                    &path,
+                    Submission {
+                        day: 1,
+                        part: Part::One,
+                        answer: answer.to_string(),
+                        outcome,
+                    },
+                )
+                .unwrap();
+            }
+
+            let reloaded = SubmissionLog::load(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+
+            assert_eq!(
+                reloaded.submissions,
+                vec![Submission {
+                    day: 1,
+                    part: Part::One,
+                    answer: String::from("10"),
+                    outcome: SubmissionOutcome::TooLow,
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn should_post_answer_with_session_cookie() {
+        let server = TestServer::start(vec![(
+            200,
+            &wrap_in_page("That's not the right answer; your answer is too low."),
+        )]);
+
+        let client = AocClient::new(test_config(&server.base_url), "abc123").unwrap();
+
+        let outcome = post_answer(&client, 2024, 7, Part::Two, "42").unwrap();
+
+        assert_eq!(outcome, SubmissionOutcome::TooLow);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/2024/day/7/answer");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].body, "level=2&answer=42");
+    }
+}
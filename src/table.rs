@@ -0,0 +1,41 @@
+#[derive(Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Lays out rows under their headers with every column padded to its widest cell
+pub fn format_table<const N: usize>(
+    headers: [&str; N],
+    align: [Align; N],
+    rows: &[[String; N]],
+) -> String {
+    let widths: [usize; N] = std::array::from_fn(|i| {
+        rows.iter()
+            .map(|row| row[i].len())
+            .chain([headers[i].len()])
+            .max()
+            .unwrap_or(0)
+    });
+
+    let format_row = |row: [&str; N]| {
+        row.iter()
+            .enumerate()
+            .map(|(i, cell)| match align[i] {
+                Align::Left => format!("{cell:<w$}", w = widths[i]),
+                Align::Right => format!("{cell:>w$}", w = widths[i]),
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_row(headers)];
+    lines.extend(
+        rows.iter()
+            .map(|row| format_row(std::array::from_fn(|i| row[i].as_str()))),
+    );
+
+    lines.join("\n")
+}
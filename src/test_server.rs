@@ -0,0 +1,87 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use tiny_http::{Header, Response, Server};
+
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+pub struct TestServer {
+    pub base_url: String,
+    requests: mpsc::Receiver<RecordedRequest>,
+}
+
+impl TestServer {
+    pub fn start(responses: Vec<(u16, &str)>) -> TestServer {
+        TestServer::start_with_delays(
+            responses
+                .into_iter()
+                .map(|(status, body)| (status, body, Duration::ZERO))
+                .collect(),
+        )
+    }
+
+    pub fn start_with_delays(responses: Vec<(u16, &str, Duration)>) -> TestServer {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let (tx, rx) = mpsc::channel();
+        let responses = responses
+            .into_iter()
+            .map(|(status, body, delay)| (status, body.to_string(), delay))
+            .collect::<Vec<_>>();
+
+        thread::spawn(move || {
+            for (status, body, delay) in responses {
+                let mut request = match server.recv() {
+                    Ok(request) => request,
+                    Err(_) => return,
+                };
+                let mut request_body = String::new();
+                let _ = request.as_reader().read_to_string(&mut request_body);
+
+                let _ = tx.send(RecordedRequest {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    headers: request
+                        .headers()
+                        .iter()
+                        .map(|h| (h.field.to_string(), h.value.to_string()))
+                        .collect(),
+                    body: request_body,
+                });
+
+                // Respond from another thread so a slow reply doesn't hold up the next request
+                thread::spawn(move || {
+                    thread::sleep(delay);
+                    let _ = request.respond(
+                        Response::from_string(body)
+                            .with_status_code(status)
+                            .with_header(Header::from_bytes("Content-Type", "text/html").unwrap()),
+                    );
+                });
+            }
+        });
+
+        TestServer {
+            base_url,
+            requests: rx,
+        }
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.try_iter().collect()
+    }
+}